@@ -0,0 +1,97 @@
+#[macro_use]
+extern crate criterion;
+extern crate rand;
+extern crate rust_checkers;
+
+use criterion::Criterion;
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::SeedableRng;
+use rust_checkers::board::PieceColor;
+use rust_checkers::game::GameEngine;
+
+/// Seed of the random games the positions are taken from, so every run measures the same boards.
+const SEED: u64 = 7;
+
+/// random_game function plays random legal moves from the opening until the game ends.
+///
+/// #Arguments
+///
+/// rng - a mutable reference of type StdRng choosing the moves.
+/// stop - a closure telling when to stop early, given the position reached.
+///
+/// #Return
+///
+/// Returns the GameEngine in the position reached.
+fn random_game(rng: &mut StdRng, stop: impl Fn(&GameEngine) -> bool) -> GameEngine {
+    let mut engine = GameEngine::new();
+    while !engine.is_game_over() && !stop(&engine) {
+        let moves = engine.legal_moves();
+        let move_desired = *moves.choose(rng).unwrap();
+        engine.move_piece(&move_desired).unwrap();
+    }
+    engine
+}
+
+/// midgame function gives a position after some plies where the side to move can capture.
+///
+/// #Return
+///
+/// Returns the GameEngine in the position.
+fn midgame() -> GameEngine {
+    let mut rng = StdRng::seed_from_u64(SEED);
+    random_game(&mut rng, |engine| {
+        engine.move_count() >= 10 && engine.has_capture()
+    })
+}
+
+/// endgame function gives a position with few pieces left, several of them kings.
+///
+/// #Return
+///
+/// Returns the GameEngine in the position.
+fn endgame() -> GameEngine {
+    let mut rng = StdRng::seed_from_u64(SEED);
+    random_game(&mut rng, |engine| {
+        let pieces = engine.piece_count(PieceColor::Black) + engine.piece_count(PieceColor::White);
+        let kings = engine.king_count(PieceColor::Black) + engine.king_count(PieceColor::White);
+        pieces <= 8 && kings >= 3
+    })
+}
+
+fn legal_moves(c: &mut Criterion) {
+    let positions = [
+        ("opening", GameEngine::new()),
+        ("midgame", midgame()),
+        ("endgame", endgame()),
+    ];
+    for (name, engine) in positions.iter() {
+        c.bench_function(&format!("legal_moves/{}", name), |b| {
+            b.iter(|| engine.legal_moves())
+        });
+    }
+}
+
+fn move_piece(c: &mut Criterion) {
+    let positions = [
+        ("opening", GameEngine::new()),
+        ("midgame", midgame()),
+        ("endgame", endgame()),
+    ];
+    for (name, engine) in positions.iter() {
+        let move_desired = engine.legal_moves()[0];
+        c.bench_function(&format!("move_piece/{}", name), |b| {
+            b.iter(|| engine.clone().move_piece(&move_desired).unwrap())
+        });
+    }
+}
+
+fn full_game(c: &mut Criterion) {
+    c.bench_function("random_game", |b| {
+        let mut rng = StdRng::seed_from_u64(SEED);
+        b.iter(|| random_game(&mut rng, |_| false))
+    });
+}
+
+criterion_group!(benches, legal_moves, move_piece, full_game);
+criterion_main!(benches);
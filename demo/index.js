@@ -8,6 +8,9 @@ fetch('./rust_checkers.wasm').then(response =>
     },
     notify_piececrowned: (x, y) => {
       console.log("A piece was crowned at (" + x + "," + y + ")");
+    },
+    notify_turnchanged: (turn) => {
+      console.log("Turn changed to " + turn);
     }
   },
 }
@@ -0,0 +1,769 @@
+use super::board::{Coordinate, Move, PieceColor};
+use super::game::{splitmix64, GameEngine};
+#[cfg(not(feature = "std"))]
+use alloc::collections::BTreeMap;
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+#[cfg(feature = "std")]
+use std::collections::HashMap;
+#[cfg(feature = "std")]
+use std::time::{Duration, Instant};
+
+/// Score given to a position where the side to move has lost.
+const LOSS_SCORE: i32 = -10_000;
+
+/// Number of plies searched when suggesting a hint.
+pub const HINT_DEPTH: u8 = 3;
+
+/// Sound opening lines in square numbers, each played from the starting position.
+const OPENING_BOOK: &[&[(u8, u8)]] = &[
+    // Old Faithful
+    &[(11, 15), (23, 19), (8, 11), (22, 17)],
+    // Single Corner
+    &[(11, 15), (22, 18), (15, 22), (25, 18)],
+    // Edinburgh
+    &[(9, 13), (22, 18), (10, 15)],
+];
+
+#[cfg(feature = "std")]
+lazy_static! {
+    /// The opening book replayed once, as the zobrist_hash of each position paired with its move.
+    static ref BOOK_TABLE: Vec<(u64, Move)> = book_table();
+}
+
+/// Evaluator trait scores positions for the search.
+pub trait Evaluator {
+    /// evaluate method scores a position.
+    ///
+    /// #Arguments
+    ///
+    /// engine - A GameEngine type reference for the position to be scored.
+    ///
+    /// #Return
+    ///
+    /// Returns an i32 value, positive when black is ahead and negative when white is ahead.
+    fn evaluate(&self, engine: &GameEngine) -> i32;
+}
+
+/// MaterialEvaluator scores a position by material alone, counting kings double.
+pub struct MaterialEvaluator;
+
+impl Evaluator for MaterialEvaluator {
+    fn evaluate(&self, engine: &GameEngine) -> i32 {
+        engine.material_balance()
+    }
+}
+
+/// MaterialPlusMobilityEvaluator scores material first and breaks ties by the number of moves.
+pub struct MaterialPlusMobilityEvaluator;
+
+impl Evaluator for MaterialPlusMobilityEvaluator {
+    fn evaluate(&self, engine: &GameEngine) -> i32 {
+        let mobility =
+            engine.mobility(PieceColor::Black) as i32 - engine.mobility(PieceColor::White) as i32;
+        engine.material_balance() * 10 + mobility
+    }
+}
+
+/// Difficulty presets for computer players, hiding the search settings behind them.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Difficulty {
+    Easy,
+    Medium,
+    Hard,
+}
+
+impl Difficulty {
+    /// depth method gives the number of plies searched at this difficulty.
+    ///
+    /// #Return
+    ///
+    /// Returns an u8 value denoting the search depth.
+    pub fn depth(self) -> u8 {
+        match self {
+            Difficulty::Easy => 1,
+            Difficulty::Medium => 3,
+            Difficulty::Hard => 5,
+        }
+    }
+
+    /// blunder_percent method gives the chance of playing a random move instead of the best one.
+    ///
+    /// #Return
+    ///
+    /// Returns an u64 value between 0 and 100.
+    pub fn blunder_percent(self) -> u64 {
+        match self {
+            Difficulty::Easy => 40,
+            Difficulty::Medium => 10,
+            Difficulty::Hard => 0,
+        }
+    }
+
+    /// evaluator method gives the evaluator scoring the search at this difficulty.
+    ///
+    /// #Return
+    ///
+    /// Returns a reference to the Evaluator.
+    pub fn evaluator(self) -> &'static dyn Evaluator {
+        match self {
+            Difficulty::Easy | Difficulty::Medium => &MaterialEvaluator,
+            Difficulty::Hard => &MaterialPlusMobilityEvaluator,
+        }
+    }
+}
+
+/// Exact scores and the depth they were searched to, keyed by zobrist hash.
+#[cfg(feature = "std")]
+type Table = HashMap<u64, (i32, u8)>;
+#[cfg(not(feature = "std"))]
+type Table = BTreeMap<u64, (i32, u8)>;
+
+struct Search<'a> {
+    evaluator: &'a dyn Evaluator,
+    /// The time after which the search is abandoned, None to search to full depth.
+    #[cfg(feature = "std")]
+    deadline: Option<Instant>,
+    table: Option<Table>,
+    nodes: u64,
+}
+
+impl<'a> Search<'a> {
+    /// new method creates a new instance of Search with an empty transposition table and no
+    /// deadline.
+    ///
+    /// #Arguments
+    ///
+    /// evaluator - an Evaluator which scores the leaves of the search.
+    ///
+    /// #Return
+    ///
+    /// Returns the instance of type Search.
+    fn new(evaluator: &'a dyn Evaluator) -> Search<'a> {
+        Search {
+            evaluator,
+            #[cfg(feature = "std")]
+            deadline: None,
+            table: Some(Table::new()),
+            nodes: 0,
+        }
+    }
+
+    /// expired method checks if the deadline of the search has passed.
+    ///
+    /// #Return
+    ///
+    /// Returns a bool value, always false without a deadline.
+    #[cfg(feature = "std")]
+    fn expired(&self) -> bool {
+        self.deadline
+            .is_some_and(|deadline| Instant::now() >= deadline)
+    }
+
+    /// expired method checks if the deadline of the search has passed. Without std there is
+    /// no clock, so searches are never timed.
+    ///
+    /// #Return
+    ///
+    /// Returns a bool value, always false.
+    #[cfg(not(feature = "std"))]
+    fn expired(&self) -> bool {
+        false
+    }
+
+    /// negamax method scores a position from the point of view of the side to move.
+    ///
+    /// #Arguments
+    ///
+    /// engine - A GameEngine type reference for the position to be searched.
+    /// depth - an u8 parameter for the remaining plies to search.
+    /// alpha - an i32 parameter for the lower bound of the search window.
+    /// beta - an i32 parameter for the upper bound of the search window.
+    /// line - a vector of Move filled with the principal variation, left empty when the score
+    ///        comes from the transposition table or no move beats alpha.
+    ///
+    /// #Return
+    ///
+    /// Returns the score wrapped in Option, None when the deadline passed mid-search.
+    fn negamax(
+        &mut self,
+        engine: &GameEngine,
+        depth: u8,
+        mut alpha: i32,
+        beta: i32,
+        line: &mut Vec<Move>,
+    ) -> Option<i32> {
+        line.clear();
+        if self.expired() {
+            return None;
+        }
+
+        let hash = engine.zobrist_hash();
+        if let Some(table) = &self.table {
+            if let Some(&(score, searched)) = table.get(&hash) {
+                if searched >= depth {
+                    return Some(score);
+                }
+            }
+        }
+        self.nodes += 1;
+
+        let moves = engine.legal_moves();
+        if moves.is_empty() {
+            return Some(LOSS_SCORE);
+        }
+        if engine.is_drawn() {
+            return Some(0);
+        }
+        if depth == 0 {
+            return Some(self.evaluate(engine));
+        }
+
+        let alpha_orig = alpha;
+        let mut best = LOSS_SCORE;
+        let mut child_line = Vec::new();
+        for move_desired in moves {
+            let score =
+                self.score_move(engine, &move_desired, depth, alpha, beta, &mut child_line)?;
+            best = best.max(score);
+            if score > alpha {
+                alpha = score;
+                line.clear();
+                line.push(move_desired);
+                line.append(&mut child_line);
+            }
+            if alpha >= beta {
+                break;
+            }
+        }
+
+        // scores outside the window are only bounds, so only exact scores are kept
+        if best > alpha_orig && best < beta {
+            if let Some(table) = &mut self.table {
+                table.insert(hash, (best, depth));
+            }
+        }
+        Some(best)
+    }
+
+    /// score_move method scores a move from the point of view of the player making it.
+    ///
+    /// #Arguments
+    ///
+    /// engine - A GameEngine type reference for the position before the move.
+    /// move_desired - A Move type reference for the move to be scored.
+    /// depth - an u8 parameter for the remaining plies to search, including this move.
+    /// alpha - an i32 parameter for the lower bound of the search window.
+    /// beta - an i32 parameter for the upper bound of the search window.
+    /// line - a vector of Move filled with the principal variation after the move.
+    ///
+    /// #Return
+    ///
+    /// Returns the score wrapped in Option, None when the deadline passed mid-search.
+    fn score_move(
+        &mut self,
+        engine: &GameEngine,
+        move_desired: &Move,
+        depth: u8,
+        alpha: i32,
+        beta: i32,
+        line: &mut Vec<Move>,
+    ) -> Option<i32> {
+        let mut child = engine.search_copy();
+        if child.move_piece(move_desired).is_err() {
+            line.clear();
+            return Some(LOSS_SCORE);
+        }
+        if child.current_turn() == engine.current_turn() {
+            // the same player moves again, so the score keeps its sign
+            self.negamax(&child, depth - 1, alpha, beta, line)
+        } else {
+            self.negamax(&child, depth - 1, -beta, -alpha, line)
+                .map(|score| -score)
+        }
+    }
+
+    /// evaluate method scores a position from the point of view of the side to move.
+    ///
+    /// #Arguments
+    ///
+    /// engine - A GameEngine type reference for the position to be scored.
+    ///
+    /// #Return
+    ///
+    /// Returns an i32 value, positive when the side to move is ahead.
+    fn evaluate(&self, engine: &GameEngine) -> i32 {
+        match engine.current_turn() {
+            PieceColor::Black => self.evaluator.evaluate(engine),
+            PieceColor::White => -self.evaluator.evaluate(engine),
+        }
+    }
+
+    /// root method searches every legal move to a fixed depth.
+    ///
+    /// #Arguments
+    ///
+    /// engine - A GameEngine type reference for the position to be searched.
+    /// depth - an u8 parameter for the number of plies to search.
+    ///
+    /// #Return
+    ///
+    /// Returns the best move wrapped in Option, None when there is no move or the deadline passed.
+    fn root(&mut self, engine: &GameEngine, depth: u8) -> Option<Move> {
+        let mut best: Option<(Move, i32)> = None;
+        let mut line = Vec::new();
+        for move_desired in engine.legal_moves() {
+            let alpha = best.map_or(LOSS_SCORE - 1, |(_, score)| score);
+            let beta = -LOSS_SCORE + 1;
+            let score = self.score_move(engine, &move_desired, depth, alpha, beta, &mut line)?;
+            if best.is_none_or(|(_, best_score)| score > best_score) {
+                best = Some((move_desired, score));
+            }
+        }
+        best.map(|(move_desired, _)| move_desired)
+    }
+}
+
+/// best_move function searches for the best move of the current player to a fixed depth.
+///
+/// The opening book is enabled, so positions in it are answered from the book without searching.
+///
+/// #Arguments
+///
+/// engine - A GameEngine type reference for the position to be searched.
+/// depth - an u8 parameter for the number of plies to search, at least 1.
+///
+/// #Return
+///
+/// Returns the best move wrapped in Option, None when the current player has no move.
+pub fn best_move(engine: &GameEngine, depth: u8) -> Option<Move> {
+    best_move_with_book(engine, depth, true)
+}
+
+/// best_move_with_book function searches for the best move of the current player to a fixed
+/// depth, consulting the opening book first only when it is enabled.
+///
+/// #Arguments
+///
+/// engine - A GameEngine type reference for the position to be searched.
+/// depth - an u8 parameter for the number of plies to search, at least 1.
+/// book_enabled - a bool value, true to answer positions in the opening book from the book.
+///
+/// #Return
+///
+/// Returns the best move wrapped in Option, None when the current player has no move.
+pub fn best_move_with_book(engine: &GameEngine, depth: u8, book_enabled: bool) -> Option<Move> {
+    let book = if book_enabled {
+        book_move(engine)
+    } else {
+        None
+    };
+    book.or_else(|| best_move_with(engine, depth, &MaterialEvaluator))
+}
+
+/// hint function suggests a move for the current player with a shallow search.
+///
+/// #Arguments
+///
+/// engine - A GameEngine type reference for the position to be searched.
+///
+/// #Return
+///
+/// Returns the suggested Move wrapped in Option, None when the current player has no move.
+pub fn hint(engine: &GameEngine) -> Option<Move> {
+    best_move(engine, HINT_DEPTH)
+}
+
+/// book_move function looks a position up in the opening book.
+///
+/// The next move of the first line passing through a position with the same zobrist_hash is
+/// taken, as long as it is legal under the engine's rules. Positions past the end of the
+/// longest line are not looked up.
+///
+/// #Arguments
+///
+/// engine - A GameEngine type reference for the position to be looked up.
+///
+/// #Return
+///
+/// Returns the book move wrapped in Option, None when the position is out of the book.
+pub fn book_move(engine: &GameEngine) -> Option<Move> {
+    let deepest = OPENING_BOOK
+        .iter()
+        .map(|line| line.len())
+        .max()
+        .unwrap_or(0);
+    if engine.move_count() as usize >= deepest {
+        return None;
+    }
+    #[cfg(feature = "std")]
+    let table: &[(u64, Move)] = &BOOK_TABLE;
+    #[cfg(not(feature = "std"))]
+    let table = &book_table();
+
+    let hash = engine.zobrist_hash();
+    let legal_moves = engine.legal_moves();
+    table
+        .iter()
+        .find(|(book_hash, move_desired)| *book_hash == hash && legal_moves.contains(move_desired))
+        .map(|&(_, move_desired)| move_desired)
+}
+
+/// book_table function replays the opening book from the starting position.
+///
+/// #Return
+///
+/// Returns the vector of the zobrist_hash of each position in the book paired with the move
+/// played from it, line by line.
+fn book_table() -> Vec<(u64, Move)> {
+    let mut table = Vec::new();
+    for line in OPENING_BOOK {
+        let mut position = GameEngine::new();
+        for &(from, to) in line.iter() {
+            let move_desired = match (
+                Coordinate::from_square_number(from, 8),
+                Coordinate::from_square_number(to, 8),
+            ) {
+                (Some(from), Some(to)) => Move { from, to },
+                _ => break,
+            };
+            table.push((position.zobrist_hash(), move_desired));
+            if position.move_piece(&move_desired).is_err() {
+                break;
+            }
+        }
+    }
+    table
+}
+
+/// best_move_with function searches for the best move of the current player with an evaluator.
+///
+/// The opening book is not consulted.
+///
+/// #Arguments
+///
+/// engine - A GameEngine type reference for the position to be searched.
+/// depth - an u8 parameter for the number of plies to search, at least 1.
+/// evaluator - an Evaluator which scores the leaves of the search.
+///
+/// #Return
+///
+/// Returns the best move wrapped in Option, None when the current player has no move.
+pub fn best_move_with(engine: &GameEngine, depth: u8, evaluator: &dyn Evaluator) -> Option<Move> {
+    let mut search = Search::new(evaluator);
+    search.root(engine, depth.max(1))
+}
+
+/// forced_capture_sequence function searches for a line winning material whatever the
+/// opponent does.
+///
+/// Each hop of a multi-jump counts as one ply, and a side left without a move loses.
+///
+/// #Arguments
+///
+/// engine - A GameEngine type reference for the position to be searched.
+/// plies - an u8 parameter for the depth of the search.
+///
+/// #Return
+///
+/// Returns the principal variation wrapped in Option, None if no line gains material.
+pub fn forced_capture_sequence(engine: &GameEngine, plies: u8) -> Option<Vec<Move>> {
+    let mut search = Search::new(&MaterialEvaluator);
+    // a score taken from the table comes without its line
+    search.table = None;
+    let mut line = Vec::new();
+    let score = search.negamax(engine, plies, LOSS_SCORE - 1, -LOSS_SCORE + 1, &mut line)?;
+    if score > search.evaluate(engine) && !line.is_empty() {
+        Some(line)
+    } else {
+        None
+    }
+}
+
+/// best_move_timed function searches with iterative deepening until the time budget runs out.
+///
+/// Depths 1, 2, 3, ... are searched in turn and the move from the deepest completed depth is
+/// kept. This relies on std::time::Instant, so it is not available to no_std builds.
+///
+/// #Arguments
+///
+/// engine - A GameEngine type reference for the position to be searched.
+/// max - a Duration parameter for the time budget of the search.
+///
+/// #Return
+///
+/// Returns the best move wrapped in Option, None when the current player has no move.
+#[cfg(feature = "std")]
+pub fn best_move_timed(engine: &GameEngine, max: Duration) -> Option<Move> {
+    let mut search = Search::new(&MaterialEvaluator);
+    search.deadline = Some(Instant::now() + max);
+    // always complete depth 1 so a legal move is returned even with a tiny budget
+    let mut best = best_move(engine, 1)?;
+    for depth in 2..=u8::MAX {
+        match search.root(engine, depth) {
+            Some(move_desired) => best = move_desired,
+            None => break,
+        }
+    }
+    Some(best)
+}
+
+/// best_move_for_difficulty function picks a move for a computer player of a given strength.
+///
+/// The seed is mixed with the position, so one seed gives varied but repeatable play over a game.
+///
+/// #Arguments
+///
+/// engine - A GameEngine type reference for the position to be searched.
+/// difficulty - a parameter of type Difficulty choosing the search settings.
+/// seed - an u64 parameter for the random choices.
+///
+/// #Return
+///
+/// Returns the chosen move wrapped in Option, None when the current player has no move.
+pub fn best_move_for_difficulty(
+    engine: &GameEngine,
+    difficulty: Difficulty,
+    seed: u64,
+) -> Option<Move> {
+    let moves = engine.legal_moves();
+    if moves.is_empty() {
+        return None;
+    }
+    let roll = splitmix64(seed ^ engine.zobrist_hash());
+    if roll % 100 < difficulty.blunder_percent() {
+        let pick = splitmix64(roll) % moves.len() as u64;
+        return Some(moves[pick as usize]);
+    }
+    best_move_with(engine, difficulty.depth(), difficulty.evaluator())
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::board::{Coordinate, GamePiece, Move, PieceColor};
+    use super::super::game::GameEngine;
+    use super::super::rules::Rules;
+    use super::{
+        best_move, best_move_for_difficulty, best_move_timed, best_move_with, best_move_with_book,
+        book_move, forced_capture_sequence, hint, Difficulty, Evaluator, MaterialEvaluator,
+        MaterialPlusMobilityEvaluator, Search, OPENING_BOOK,
+    };
+    use std::time::Duration;
+
+    #[test]
+    fn best_move_capture_success() {
+        let mut engine = GameEngine::new();
+        engine.move_piece(&Move::new((2, 5), (3, 4))).unwrap();
+        engine.move_piece(&Move::new((1, 2), (2, 3))).unwrap();
+        // white has left a piece hanging on (2, 3)
+        let res = best_move(&engine, 1);
+        assert_eq!(res, Some(Move::new((3, 4), (1, 2))));
+    }
+
+    #[test]
+    fn best_move_skips_blocked_jump() {
+        let mut engine = GameEngine::new();
+        engine.move_piece(&Move::new((0, 5), (1, 4))).unwrap();
+        engine.move_piece(&Move::new((1, 2), (2, 3))).unwrap();
+        // (2, 3) can't be taken since white holds the landing square (3, 2)
+        let res = best_move(&engine, 1).unwrap();
+        assert_ne!(res, Move::new((1, 4), (3, 2)));
+        assert!(engine.legal_moves().contains(&res));
+    }
+
+    #[test]
+    fn hint_success() {
+        let engine = GameEngine::new();
+        let res = hint(&engine).unwrap();
+        assert!(engine.legal_moves().contains(&res));
+
+        let empty = GameEngine::from_pieces(&[], PieceColor::Black).unwrap();
+        assert_eq!(hint(&empty), None);
+    }
+
+    #[test]
+    fn difficulty_legal_move_success() {
+        let engine = GameEngine::new();
+        for &difficulty in [Difficulty::Easy, Difficulty::Medium, Difficulty::Hard].iter() {
+            for seed in 0..5 {
+                let res = best_move_for_difficulty(&engine, difficulty, seed).unwrap();
+                assert!(engine.legal_moves().contains(&res));
+            }
+        }
+    }
+
+    #[test]
+    fn difficulty_easy_blunders() {
+        let mut engine = GameEngine::new();
+        engine.move_piece(&Move::new((2, 5), (3, 4))).unwrap();
+        engine.move_piece(&Move::new((1, 2), (2, 3))).unwrap();
+        let capture = Move::new((3, 4), (1, 2));
+
+        let hard = best_move_with(&engine, 5, &MaterialPlusMobilityEvaluator);
+        for seed in 0..3 {
+            assert_eq!(
+                best_move_for_difficulty(&engine, Difficulty::Hard, seed),
+                hard
+            );
+        }
+        assert_eq!(
+            best_move_with(&engine, 1, &MaterialEvaluator),
+            Some(capture)
+        );
+        assert!((0..50).any(|seed| {
+            best_move_for_difficulty(&engine, Difficulty::Easy, seed) != Some(capture)
+        }));
+    }
+
+    #[test]
+    fn opening_book_success() {
+        let mut engine = GameEngine::new();
+        // 11-15
+        assert_eq!(best_move(&engine, 4), Some(Move::new((2, 5), (3, 4))));
+
+        engine.move_piece(&Move::new((2, 5), (3, 4))).unwrap();
+        engine.move_piece(&Move::new((3, 2), (2, 3))).unwrap();
+        // 23-19 is answered by 8-11
+        assert_eq!(book_move(&engine), Some(Move::new((1, 6), (2, 5))));
+
+        engine.move_piece(&Move::new((1, 6), (2, 5))).unwrap();
+        engine.move_piece(&Move::new((1, 2), (0, 3))).unwrap();
+        assert_eq!(book_move(&engine), None);
+
+        // the starting position resumed deep into a game is past every line
+        let mut engine = GameEngine::new();
+        engine.set_move_count(4);
+        assert_eq!(book_move(&engine), None);
+    }
+
+    #[test]
+    fn opening_book_enabled() {
+        let engine = GameEngine::new();
+        let book = book_move(&engine);
+        assert_eq!(book, Some(Move::new((2, 5), (3, 4))));
+        assert_eq!(best_move_with_book(&engine, 1, true), book);
+        assert_eq!(best_move(&engine, 1), book);
+    }
+
+    #[test]
+    fn opening_book_disabled() {
+        let engine = GameEngine::new();
+        // a one ply search on material alone settles on a different opening move
+        let searched = best_move_with(&engine, 1, &MaterialEvaluator);
+        assert_ne!(searched, book_move(&engine));
+        assert_eq!(best_move_with_book(&engine, 1, false), searched);
+    }
+
+    #[test]
+    fn opening_book_lines_legal() {
+        for line in OPENING_BOOK {
+            let mut engine = GameEngine::new();
+            for &(from, to) in line.iter() {
+                let move_desired = Move {
+                    from: Coordinate::from_square_number(from, 8).unwrap(),
+                    to: Coordinate::from_square_number(to, 8).unwrap(),
+                };
+                assert!(book_move(&engine).is_some());
+                engine.move_piece(&move_desired).unwrap();
+            }
+        }
+    }
+
+    #[test]
+    fn best_move_timed_success() {
+        let engine = GameEngine::new();
+        let res = best_move_timed(&engine, Duration::from_millis(200)).unwrap();
+        assert!(engine.legal_moves().contains(&res));
+    }
+
+    #[test]
+    fn best_move_timed_no_moves() {
+        // a lone white piece with black to move
+        let mut bytes = vec![0u8; 13];
+        bytes[0] = 2;
+        let engine = GameEngine::from_bytes(&bytes).unwrap();
+        let res = best_move_timed(&engine, Duration::from_millis(50));
+        assert_eq!(res, None);
+    }
+
+    #[test]
+    fn transposition_table_success() {
+        let mut engine = GameEngine::new();
+        engine.move_piece(&Move::new((2, 5), (3, 4))).unwrap();
+
+        let mut with_table = Search::new(&MaterialEvaluator);
+        let mut without_table = Search::new(&MaterialEvaluator);
+        without_table.table = None;
+
+        let move_with = with_table.root(&engine, 6);
+        let move_without = without_table.root(&engine, 6);
+        assert_eq!(move_with, move_without);
+        assert!(with_table.nodes < without_table.nodes);
+    }
+
+    /// position function builds a black-to-move position through the byte encoding.
+    struct KingHeavyEvaluator;
+
+    impl Evaluator for KingHeavyEvaluator {
+        fn evaluate(&self, engine: &GameEngine) -> i32 {
+            let men = engine.piece_count(PieceColor::Black) as i32
+                - engine.piece_count(PieceColor::White) as i32;
+            let kings = engine.king_count(PieceColor::Black) as i32
+                - engine.king_count(PieceColor::White) as i32;
+            men + kings * 9
+        }
+    }
+
+    #[test]
+    fn evaluator_changes_choice() {
+        // black can either crown the man on (2, 1) or double jump with the man on (7, 6)
+        let black = GamePiece::new(PieceColor::Black);
+        let white = GamePiece::new(PieceColor::White);
+        let pieces = [
+            (Coordinate(2, 1), black),
+            (Coordinate(7, 6), black),
+            (Coordinate(6, 5), white),
+            (Coordinate(4, 3), white),
+            (Coordinate(7, 0), GamePiece::crowned(white)),
+        ];
+        let engine = GameEngine::from_pieces(&pieces, PieceColor::Black).unwrap();
+
+        let material = best_move_with(&engine, 2, &MaterialEvaluator).unwrap();
+        assert_eq!(material, Move::new((7, 6), (5, 4)));
+
+        let kings = best_move_with(&engine, 2, &KingHeavyEvaluator).unwrap();
+        assert_eq!(kings.from, Coordinate(2, 1));
+        assert_eq!(kings.to.1, 0);
+
+        assert!(best_move_with(&engine, 2, &MaterialPlusMobilityEvaluator).is_some());
+    }
+
+    #[test]
+    fn forced_capture_sequence_success() {
+        let black = GamePiece::new(PieceColor::Black);
+        let white = GamePiece::new(PieceColor::White);
+        let pieces = [
+            (Coordinate(4, 5), black),
+            (Coordinate(5, 6), black),
+            (Coordinate(2, 5), black),
+            (Coordinate(6, 7), black),
+            (Coordinate(2, 3), white),
+            (Coordinate(4, 3), white),
+        ];
+        let engine =
+            GameEngine::from_pieces_with_rules(&pieces, PieceColor::Black, Rules::american())
+                .unwrap();
+
+        // black gives one man to take two
+        assert_eq!(
+            forced_capture_sequence(&engine, 4),
+            Some(vec![
+                Move::new((4, 5), (3, 4)),
+                Move::new((2, 3), (4, 5)),
+                Move::new((5, 6), (3, 4)),
+                Move::new((3, 4), (5, 2)),
+            ])
+        );
+        assert_eq!(forced_capture_sequence(&engine, 2), None);
+    }
+}
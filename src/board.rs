@@ -1,9 +1,65 @@
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
+/// Number of squares along a side of the board.
+pub const BOARD_SIZE: usize = 8;
+
 #[derive(Debug, Copy, Clone, PartialEq)]
 pub enum PieceColor {
     White,
     Black,
 }
 
+impl PieceColor {
+    /// opponent method gives the color of the other player.
+    ///
+    /// #Return
+    ///
+    /// Returns the PieceColor of the opposing side.
+    pub fn opponent(self) -> PieceColor {
+        match self {
+            PieceColor::White => PieceColor::Black,
+            PieceColor::Black => PieceColor::White,
+        }
+    }
+
+    /// crown_row method gives the row where this color's men are crowned.
+    ///
+    /// #Arguments
+    ///
+    /// board_size - an usize parameter for the number of squares along a side of the board.
+    /// orientation - a parameter of type Orientation telling which end each color starts from.
+    ///
+    /// #Return
+    ///
+    /// Returns an usize value denoting the y coordinate of the far row.
+    pub fn crown_row(self, board_size: usize, orientation: Orientation) -> usize {
+        let black_row = match orientation {
+            Orientation::BlackHigh => 0,
+            Orientation::BlackLow => board_size - 1,
+        };
+        match self {
+            PieceColor::Black => black_row,
+            PieceColor::White => board_size - 1 - black_row,
+        }
+    }
+}
+
+/// Orientation tells which end of the board each color starts from.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Orientation {
+    /// Black starts on the highest rows and moves toward y = 0, as GameEngine sets up.
+    BlackHigh,
+    /// Black starts on row 0 and moves toward the highest rows.
+    BlackLow,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum PieceKind {
+    Man,
+    King,
+}
+
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub struct GamePiece {
     pub color: PieceColor,
@@ -43,13 +99,44 @@ impl GamePiece {
             crowned: true,
         }
     }
+    /// kind method tells whether the piece is a man or a king.
+    ///
+    /// #Return
+    ///
+    /// Returns the PieceKind of the piece.
+    pub fn kind(&self) -> PieceKind {
+        if self.crowned {
+            PieceKind::King
+        } else {
+            PieceKind::Man
+        }
+    }
 }
 
-#[derive(Debug, Clone, PartialEq, Copy)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Copy)]
 pub struct Coordinate(pub usize, pub usize);
 
 impl Coordinate {
 
+    /// new method creates a new instance of Coordinate if it lies on the board.
+    ///
+    /// #Arguments
+    ///
+    /// x_coord - an usize parameter for the x coordinate.
+    /// y_coord - an usize parameter for the y coordinate.
+    ///
+    /// #Return
+    ///
+    /// Returns the Coordinate wrapped in Option, None when it is off the board.
+    pub fn new(x_coord: usize, y_coord: usize) -> Option<Coordinate> {
+        let coord = Coordinate(x_coord, y_coord);
+        if coord.on_board() {
+            Some(coord)
+        } else {
+            None
+        }
+    }
+
     /// on_board method checks if the piece is on the board.
     ///
     /// #Return
@@ -57,7 +144,7 @@ impl Coordinate {
     /// Returns a bool value denoting if the piece is on the board.
     pub fn on_board(self) -> bool {
         let Coordinate(x_coord, y_coord) = self;
-        x_coord <= 7 && y_coord <= 7
+        x_coord < BOARD_SIZE && y_coord < BOARD_SIZE
     }
 
     /// jump_targets_from method gives all the location to which a jump can be made.
@@ -102,9 +189,140 @@ impl Coordinate {
         }
         moves.into_iter()
     }
+
+    /// orthogonal_jump_targets_from method gives the locations two squares away along the rank and file.
+    ///
+    /// #Return
+    ///
+    /// Returns the iterator over a vector containing Coordinate type objects denoting targets for jump.
+    pub fn orthogonal_jump_targets_from(&self) -> impl Iterator<Item = Coordinate> {
+        let mut jumps = Vec::new();
+        let Coordinate(x_coord, y_coord) = *self;
+        if y_coord >= 2 {
+            jumps.push(Coordinate(x_coord, y_coord - 2));
+        }
+        jumps.push(Coordinate(x_coord + 2, y_coord));
+        jumps.push(Coordinate(x_coord, y_coord + 2));
+        if x_coord >= 2 {
+            jumps.push(Coordinate(x_coord - 2, y_coord));
+        }
+        jumps.into_iter()
+    }
+
+    /// orthogonal_move_targets_from method gives the neighbouring locations along the rank and file.
+    ///
+    /// #Return
+    ///
+    /// Returns the iterator over a vector containing Coordinate type objects denoting targets for the move.
+    pub fn orthogonal_move_targets_from(&self) -> impl Iterator<Item = Coordinate> {
+        let mut moves = Vec::new();
+        let Coordinate(x_coord, y_coord) = *self;
+        if y_coord >= 1 {
+            moves.push(Coordinate(x_coord, y_coord - 1));
+        }
+        moves.push(Coordinate(x_coord + 1, y_coord));
+        moves.push(Coordinate(x_coord, y_coord + 1));
+        if x_coord >= 1 {
+            moves.push(Coordinate(x_coord - 1, y_coord));
+        }
+        moves.into_iter()
+    }
+
+    /// diagonal_targets_from method gives every square on the board along the four diagonals.
+    ///
+    /// #Return
+    ///
+    /// Returns the iterator over a vector containing Coordinate type objects, nearest squares first.
+    pub fn diagonal_targets_from(&self) -> impl Iterator<Item = Coordinate> {
+        let mut targets = Vec::new();
+        let Coordinate(x_coord, y_coord) = *self;
+        for &(step_x, step_y) in [(1, -1), (1, 1), (-1, -1), (-1, 1)].iter() {
+            let mut x_next = x_coord as isize + step_x;
+            let mut y_next = y_coord as isize + step_y;
+            let size = BOARD_SIZE as isize;
+            while (0..size).contains(&x_next) && (0..size).contains(&y_next) {
+                targets.push(Coordinate(x_next as usize, y_next as usize));
+                x_next += step_x;
+                y_next += step_y;
+            }
+        }
+        targets.into_iter()
+    }
+
+    /// squares_between method gives the squares strictly between two points of a diagonal.
+    ///
+    /// #Arguments
+    ///
+    /// other - a parameter of type Coordinate denoting the other end of the diagonal.
+    ///
+    /// #Return
+    ///
+    /// Returns a vector of Coordinate wrapped in Option, None if the points aren't on a diagonal.
+    pub fn squares_between(self, other: Coordinate) -> Option<Vec<Coordinate>> {
+        let Coordinate(from_x, from_y) = self;
+        let Coordinate(to_x, to_y) = other;
+        let distance = from_x.abs_diff(to_x);
+        if distance == 0 || distance != from_y.abs_diff(to_y) {
+            return None;
+        }
+        let step_x = if to_x > from_x { 1 } else { -1 };
+        let step_y = if to_y > from_y { 1 } else { -1 };
+        Some(
+            (1..distance as isize)
+                .map(|step| {
+                    Coordinate(
+                        (from_x as isize + step * step_x) as usize,
+                        (from_y as isize + step * step_y) as usize,
+                    )
+                })
+                .collect(),
+        )
+    }
+
+    /// to_square_number method gives the standard checkers number of the square.
+    ///
+    /// Squares are numbered from 1 along the dark squares, starting from black's back row.
+    ///
+    /// #Arguments
+    ///
+    /// board_size - an usize parameter for the number of squares along a side of the board.
+    ///
+    /// #Return
+    ///
+    /// Returns the square number wrapped in Option, None for light or off-board squares.
+    pub fn to_square_number(self, board_size: usize) -> Option<u8> {
+        let Coordinate(x_coord, y_coord) = self;
+        if x_coord >= board_size || y_coord >= board_size || (x_coord + y_coord) % 2 == 0 {
+            return None;
+        }
+        let row = board_size - 1 - y_coord;
+        let index = (board_size - 1 - x_coord) / 2;
+        Some((row * board_size / 2 + index + 1) as u8)
+    }
+
+    /// from_square_number method gives the coordinate of a standard checkers square number.
+    ///
+    /// #Arguments
+    ///
+    /// number - an u8 parameter for the square number.
+    /// board_size - an usize parameter for the number of squares along a side of the board.
+    ///
+    /// #Return
+    ///
+    /// Returns the Coordinate wrapped in Option, None if the number is out of range.
+    pub fn from_square_number(number: u8, board_size: usize) -> Option<Coordinate> {
+        let per_row = board_size / 2;
+        if number == 0 || number as usize > per_row * board_size {
+            return None;
+        }
+        let row = (number as usize - 1) / per_row;
+        let index = (number as usize - 1) % per_row;
+        let x_coord = board_size - 2 - 2 * index + row % 2;
+        Some(Coordinate(x_coord, board_size - 1 - row))
+    }
 }
 
-#[derive(Debug, Clone, PartialEq, Copy)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Copy)]
 pub struct Move {
     pub from: Coordinate,
     pub to: Coordinate,
@@ -128,4 +346,33 @@ impl Move {
             to: Coordinate(to.0, to.1),
         }
     }
+
+    /// pack method packs the squares of an on-board move into 12 bits.
+    ///
+    /// #Return
+    ///
+    /// Returns an u16 value holding (from_y * 8 + from_x) in bits 6-11 and (to_y * 8 + to_x) in bits 0-5.
+    pub fn pack(&self) -> u16 {
+        let Coordinate(from_x, from_y) = self.from;
+        let Coordinate(to_x, to_y) = self.to;
+        (((from_y * 8 + from_x) << 6) | (to_y * 8 + to_x)) as u16
+    }
+
+    /// unpack method creates a new instance of Move from its packed form.
+    ///
+    /// #Arguments
+    ///
+    /// packed - an u16 value produced by pack.
+    ///
+    /// #Return
+    ///
+    /// Returns the Move wrapped in Option, None when bits above the low 12 are set.
+    pub fn unpack(packed: u16) -> Option<Move> {
+        if packed >> 12 != 0 {
+            return None;
+        }
+        let from = (packed >> 6) as usize;
+        let to = (packed & 0x3f) as usize;
+        Some(Move::new((from % 8, from / 8), (to % 8, to / 8)))
+    }
 }
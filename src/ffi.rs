@@ -0,0 +1,618 @@
+use super::ai;
+use super::board::{Coordinate, GamePiece, Move, PieceColor};
+use super::game::{GameEngine, GameObserver, MoveError};
+use mut_static::MutStatic;
+use std::cell::RefCell;
+use std::convert::TryFrom;
+use std::ffi::CString;
+use std::os::raw::c_char;
+
+extern "C" {
+    fn notify_piecemoved(from_x: i32, from_y: i32, to_x: i32, to_y: i32);
+    fn notify_piececrowned(x_coord: i32, y_coord: i32);
+    fn notify_turnchanged(new_turn: i32);
+}
+
+lazy_static! {
+    pub static ref GAME_ENGINE: MutStatic<GameEngine> = MutStatic::from(GameEngine::new());
+}
+
+thread_local! {
+    /// The reason the last move made through the FFI on this thread failed, empty after a success.
+    static LAST_ERROR: RefCell<CString> = RefCell::new(CString::default());
+}
+
+/// Error code returned when a coordinate passed from the host lies outside 0..8.
+pub const INVALID_COORDINATE: i32 = -2;
+
+/// move_piece function is exposed to be used in js file.
+///
+/// #Arguments
+///
+/// from_x - an usize parameter for x coordinate of starting location.
+/// from_y - an usize parameter for y coordinate of starting location.
+/// to_x - an usize parameter for x coordinate of final location.
+/// to_y - an usize parameter for y coordinate of final location.
+///
+/// #Return
+///
+/// Returns an i32 value denoting success status of move, or INVALID_COORDINATE.
+#[no_mangle]
+pub extern "C" fn move_piece(from_x: i32, from_y: i32, to_x: i32, to_y: i32) -> i32 {
+    let mut engine = GAME_ENGINE.write().unwrap();
+    apply_move(&mut engine, from_x, from_y, to_x, to_y, &mut HostObserver)
+}
+
+/// apply_move function makes a move given as raw FFI coordinates.
+///
+/// #Arguments
+///
+/// engine - a mutable reference of type GameEngine to make the move on.
+/// from_x - an i32 parameter for x coordinate of starting location.
+/// from_y - an i32 parameter for y coordinate of starting location.
+/// to_x - an i32 parameter for x coordinate of final location.
+/// to_y - an i32 parameter for y coordinate of final location.
+/// observer - a GameObserver which is told about the move.
+///
+/// #Return
+///
+/// Returns an i32 value, 1 when the move was made, INVALID_COORDINATE when a coordinate is
+/// off the board and 0 otherwise.
+fn apply_move(
+    engine: &mut GameEngine,
+    from_x: i32,
+    from_y: i32,
+    to_x: i32,
+    to_y: i32,
+    observer: &mut dyn GameObserver,
+) -> i32 {
+    let move_made = match (ffi_coordinate(from_x, from_y), ffi_coordinate(to_x, to_y)) {
+        (Some(from), Some(to)) => Move { from, to },
+        _ => {
+            set_last_error("coordinate is off the board");
+            return INVALID_COORDINATE;
+        }
+    };
+    let res = engine.move_piece_observed(&move_made, observer);
+    match res {
+        Ok(_) => {
+            set_last_error("");
+            1
+        }
+        Err(err) => {
+            set_last_error(error_message(err));
+            0
+        }
+    }
+}
+
+/// error_message function describes a MoveError for players.
+///
+/// #Arguments
+///
+/// err - a parameter of type MoveError to be described.
+///
+/// #Return
+///
+/// Returns a static str with the human readable reason.
+fn error_message(err: MoveError) -> &'static str {
+    match err {
+        MoveError::IllegalMove => "illegal move",
+        MoveError::PassNotAllowed => "passing is not allowed",
+        MoveError::GameOver => "the game is over",
+        MoveError::NoDrawOffer => "no draw has been offered",
+        MoveError::InvalidNotation => "the move could not be read",
+        MoveError::OffBoard => "coordinate is off the board",
+    }
+}
+
+/// set_last_error function records the reason the last FFI move failed.
+///
+/// #Arguments
+///
+/// message - a str reference holding the reason, empty after a success.
+fn set_last_error(message: &str) {
+    let message = CString::new(message).unwrap_or_default();
+    LAST_ERROR.with(|last_error| *last_error.borrow_mut() = message);
+}
+
+/// last_error function gives the reason the last FFI move on this thread failed.
+///
+/// #Return
+///
+/// Returns the String reason, empty if the last move succeeded.
+pub fn last_error() -> String {
+    LAST_ERROR.with(|last_error| last_error.borrow().to_string_lossy().into_owned())
+}
+
+/// last_error_ptr function is exposed to be used in js file.
+///
+/// #Return
+///
+/// Returns a pointer to the NUL terminated reason the last move failed, empty if it succeeded.
+/// The pointer stays valid until the next move on the same thread.
+#[no_mangle]
+pub extern "C" fn last_error_ptr() -> *const c_char {
+    LAST_ERROR.with(|last_error| last_error.borrow().as_ptr())
+}
+
+/// pass_turn function is exposed to be used in js file.
+///
+/// #Return
+///
+/// Returns an i32 value denoting whether the turn was passed.
+#[no_mangle]
+pub extern "C" fn pass_turn() -> i32 {
+    let mut engine = GAME_ENGINE.write().unwrap();
+    apply_pass(&mut engine, &mut HostObserver)
+}
+
+/// apply_pass function hands the turn to the other player.
+///
+/// #Arguments
+///
+/// engine - a mutable reference of type GameEngine to pass the turn on.
+/// observer - a GameObserver which is told about the turn change.
+///
+/// #Return
+///
+/// Returns an i32 value, 1 when the turn was passed and 0 otherwise.
+fn apply_pass(engine: &mut GameEngine, observer: &mut dyn GameObserver) -> i32 {
+    match engine.pass_turn_observed(observer) {
+        Ok(()) => {
+            set_last_error("");
+            1
+        }
+        Err(err) => {
+            set_last_error(error_message(err));
+            0
+        }
+    }
+}
+
+/// move_piece_packed function is exposed to be used in js file.
+///
+/// #Arguments
+///
+/// packed - an i32 parameter holding the move packed as by Move::pack.
+///
+/// #Return
+///
+/// Returns an i32 value denoting success status of move, or INVALID_COORDINATE.
+#[no_mangle]
+pub extern "C" fn move_piece_packed(packed: i32) -> i32 {
+    let mut engine = GAME_ENGINE.write().unwrap();
+    apply_packed_move(&mut engine, packed, &mut HostObserver)
+}
+
+/// apply_packed_move function makes a move given in its packed form.
+///
+/// #Arguments
+///
+/// engine - a mutable reference of type GameEngine to make the move on.
+/// packed - an i32 parameter holding the move packed as by Move::pack.
+/// observer - a GameObserver which is told about the move.
+///
+/// #Return
+///
+/// Returns an i32 value, as returned by apply_move, or INVALID_COORDINATE when packed is out of range.
+fn apply_packed_move(engine: &mut GameEngine, packed: i32, observer: &mut dyn GameObserver) -> i32 {
+    let move_made = match u16::try_from(packed).ok().and_then(Move::unpack) {
+        Some(move_made) => move_made,
+        None => {
+            set_last_error("coordinate is off the board");
+            return INVALID_COORDINATE;
+        }
+    };
+    let Coordinate(from_x, from_y) = move_made.from;
+    let Coordinate(to_x, to_y) = move_made.to;
+    apply_move(
+        engine,
+        from_x as i32,
+        from_y as i32,
+        to_x as i32,
+        to_y as i32,
+        observer,
+    )
+}
+
+/// HostObserver forwards game events to the notify functions imported from the JS host.
+struct HostObserver;
+
+impl GameObserver for HostObserver {
+    fn piece_moved(&mut self, move_made: &Move) {
+        let Coordinate(from_x, from_y) = move_made.from;
+        let Coordinate(to_x, to_y) = move_made.to;
+        unsafe {
+            notify_piecemoved(from_x as i32, from_y as i32, to_x as i32, to_y as i32);
+        }
+    }
+
+    fn piece_crowned(&mut self, coord: Coordinate) {
+        let Coordinate(x_coord, y_coord) = coord;
+        unsafe {
+            notify_piececrowned(x_coord as i32, y_coord as i32);
+        }
+    }
+
+    fn turn_changed(&mut self, new_turn: PieceColor) {
+        unsafe {
+            notify_turnchanged(GamePiece::new(new_turn).into());
+        }
+    }
+}
+
+/// get_piece function is exposed to be used in js file.
+///
+/// #Arguments
+///
+/// x_coord - an i32 parameter for x coordinate.
+/// y_coord - an i32 parameter for y coordinate.
+///
+/// #Return
+///
+/// Returns an i32 value denoting the encoded piece, -1 for an empty square, or INVALID_COORDINATE.
+#[no_mangle]
+pub extern "C" fn get_piece(x_coord: i32, y_coord: i32) -> i32 {
+    let coord = match ffi_coordinate(x_coord, y_coord) {
+        Some(coord) => coord,
+        None => return INVALID_COORDINATE,
+    };
+    let engine = GAME_ENGINE.read().unwrap();
+
+    let piece_from_board = engine.get_piece(coord);
+    match piece_from_board {
+        Ok(Some(piece)) => piece.into(),
+        Ok(None) => -1,
+        Err(_) => -1,
+    }
+}
+
+/// ffi_coordinate function checks a coordinate passed from the host before it is used.
+///
+/// #Arguments
+///
+/// x_coord - an i32 parameter for x coordinate.
+/// y_coord - an i32 parameter for y coordinate.
+///
+/// #Return
+///
+/// Returns the Coordinate wrapped in Option, None when either value is outside 0..8.
+fn ffi_coordinate(x_coord: i32, y_coord: i32) -> Option<Coordinate> {
+    if x_coord < 0 || y_coord < 0 {
+        return None;
+    }
+    Coordinate::new(x_coord as usize, y_coord as usize)
+}
+
+/// get_board function is exposed to be used in js file.
+///
+/// Writes the encoded piece of all 64 squares, or -1 for an empty square, in row-major
+/// order so that the square (x, y) is written at index y * 8 + x.
+///
+/// #Arguments
+///
+/// out_ptr - a pointer to a caller-provided buffer of BOARD_CELLS i32 values.
+///
+/// # Safety
+///
+/// out_ptr must be non-null, aligned and valid for writes of BOARD_CELLS i32 values,
+/// for example a pointer into an Int32Array of length 64 in the wasm memory.
+#[no_mangle]
+pub unsafe extern "C" fn get_board(out_ptr: *mut i32) {
+    let engine = GAME_ENGINE.read().unwrap();
+    let out = &mut *(out_ptr as *mut [i32; BOARD_CELLS]);
+    fill_board(&engine, out);
+}
+
+/// Number of i32 values written by get_board.
+pub const BOARD_CELLS: usize = 64;
+
+/// fill_board function writes the encoded board into a buffer.
+///
+/// #Arguments
+///
+/// engine - A GameEngine type reference for the position to be encoded.
+/// out - a mutable reference to the buffer of BOARD_CELLS values to be filled.
+fn fill_board(engine: &GameEngine, out: &mut [i32; BOARD_CELLS]) {
+    for (index, cell) in out.iter_mut().enumerate() {
+        *cell = match engine.get_piece(Coordinate(index % 8, index / 8)) {
+            Ok(Some(piece)) => piece.into(),
+            _ => -1,
+        };
+    }
+}
+
+/// get_last_move function is exposed to be used in js file.
+///
+/// #Return
+///
+/// Returns the last move packed by Move::pack, or -1 when no move has been made yet.
+#[no_mangle]
+pub extern "C" fn get_last_move() -> i32 {
+    let engine = GAME_ENGINE.read().unwrap();
+
+    match engine.last_move() {
+        Some(last_move) => i32::from(last_move.pack()),
+        None => -1,
+    }
+}
+
+/// game_state_json function is exposed to be used in js file.
+///
+/// Copies as much of GameEngine::state_json as fits into the buffer, so the caller can
+/// retry with a larger buffer when the returned length exceeds out_len.
+///
+/// #Arguments
+///
+/// out_ptr - a pointer to a caller-provided buffer for the UTF-8 encoded JSON.
+/// out_len - an usize parameter for the length of the buffer in bytes.
+///
+/// #Return
+///
+/// Returns an i32 value denoting the full length of the JSON in bytes.
+///
+/// # Safety
+///
+/// out_ptr must be valid for writes of out_len bytes, or out_len must be 0.
+#[no_mangle]
+pub unsafe extern "C" fn game_state_json(out_ptr: *mut u8, out_len: usize) -> i32 {
+    let engine = GAME_ENGINE.read().unwrap();
+    let json = engine.state_json();
+    let len = json.len().min(out_len);
+    if len > 0 {
+        std::ptr::copy_nonoverlapping(json.as_ptr(), out_ptr, len);
+    }
+    json.len() as i32
+}
+
+/// evaluate_position function is exposed to be used in js file.
+///
+/// Men count 1 and kings count 2, so a positive value means black is ahead and a negative
+/// value means white is ahead.
+///
+/// #Arguments
+///
+/// #Return
+///
+/// Returns an i32 value denoting the material balance from black's perspective.
+#[no_mangle]
+pub extern "C" fn evaluate_position() -> i32 {
+    let engine = GAME_ENGINE.read().unwrap();
+
+    engine.material_balance()
+}
+
+/// get_hint function is exposed to be used in js file.
+///
+/// Writes from_x, from_y, to_x and to_y of the suggested move, or -1 in all four values
+/// when the current player has no move.
+///
+/// #Arguments
+///
+/// out - a pointer to a caller-provided buffer of 4 i32 values.
+///
+/// # Safety
+///
+/// out must be non-null, aligned and valid for writes of 4 i32 values.
+#[no_mangle]
+pub unsafe extern "C" fn get_hint(out: *mut i32) {
+    let engine = GAME_ENGINE.read().unwrap();
+    let out = &mut *(out as *mut [i32; 4]);
+    *out = match ai::hint(&engine) {
+        Some(Move {
+            from: Coordinate(from_x, from_y),
+            to: Coordinate(to_x, to_y),
+        }) => [from_x as i32, from_y as i32, to_x as i32, to_y as i32],
+        None => [-1; 4],
+    };
+}
+
+/// get_current_turn function is exposed to be used in js file.
+///
+/// #Arguments
+///
+/// #Return
+///
+/// Returns an i32 value denoting current turn owner.
+#[no_mangle]
+pub extern "C" fn get_current_turn() -> i32 {
+    let engine = GAME_ENGINE.read().unwrap();
+
+    GamePiece::new(engine.current_turn()).into()
+}
+
+/// get_move_count function is exposed to be used in js file.
+///
+/// The count is of plies, one for each player's turn, so a full move by both sides adds 2.
+/// Use GameEngine::full_move_number for the conventional move number.
+///
+/// #Return
+///
+/// Returns an i32 value denoting the number of plies played.
+#[no_mangle]
+pub extern "C" fn get_move_count() -> i32 {
+    let engine = GAME_ENGINE.read().unwrap();
+    ply_count(&engine)
+}
+
+/// ply_count function gives the number of plies played as an FFI value.
+///
+/// #Arguments
+///
+/// engine - a reference of type GameEngine to be read.
+///
+/// #Return
+///
+/// Returns an i32 value denoting the number of plies played, saturating at i32::MAX.
+fn ply_count(engine: &GameEngine) -> i32 {
+    i32::try_from(engine.move_count()).unwrap_or(i32::MAX)
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::board::{Move, PieceColor};
+    use super::super::game::{GameEngine, GameObserver};
+    use super::super::rules::RulesBuilder;
+    use super::{
+        apply_move, apply_packed_move, apply_pass, fill_board, get_piece, last_error,
+        last_error_ptr, ply_count, INVALID_COORDINATE,
+    };
+    use proptest::prelude::*;
+    use std::ffi::CStr;
+
+    struct NullObserver;
+
+    impl GameObserver for NullObserver {}
+
+    proptest! {
+        #[test]
+        fn apply_move_never_panics(
+            from_x in any::<i32>(),
+            from_y in any::<i32>(),
+            to_x in any::<i32>(),
+            to_y in any::<i32>(),
+        ) {
+            let mut engine = GameEngine::new();
+            let res = apply_move(&mut engine, from_x, from_y, to_x, to_y, &mut NullObserver);
+            prop_assert!(res == 0 || res == 1 || res == INVALID_COORDINATE);
+        }
+
+        #[test]
+        fn apply_move_near_board_never_panics(
+            from_x in -2i32..10,
+            from_y in -2i32..10,
+            to_x in -2i32..10,
+            to_y in -2i32..10,
+        ) {
+            let mut engine = GameEngine::new();
+            let res = apply_move(&mut engine, from_x, from_y, to_x, to_y, &mut NullObserver);
+            prop_assert!(res == 0 || res == 1 || res == INVALID_COORDINATE);
+        }
+    }
+
+    #[test]
+    fn apply_move_regressions() {
+        let seeds = [
+            (-1, -1, -1, -1),
+            (0, 5, -1, 4),
+            (i32::MIN, 5, i32::MAX, 4),
+            (7, 8, 6, 7),
+        ];
+        for &(from_x, from_y, to_x, to_y) in seeds.iter() {
+            let mut engine = GameEngine::new();
+            let res = apply_move(&mut engine, from_x, from_y, to_x, to_y, &mut NullObserver);
+            assert_eq!(res, INVALID_COORDINATE);
+        }
+
+        let mut engine = GameEngine::new();
+        assert_eq!(apply_move(&mut engine, 0, 5, 1, 4, &mut NullObserver), 1);
+    }
+
+    #[test]
+    fn invalid_coordinate_failure() {
+        let mut engine = GameEngine::new();
+        let before = engine.to_bytes();
+        let res = apply_move(&mut engine, -1, 5, 99, 4, &mut NullObserver);
+        assert_eq!(res, INVALID_COORDINATE);
+        assert_eq!(engine.to_bytes(), before);
+
+        assert_eq!(get_piece(-1, 0), INVALID_COORDINATE);
+        assert_eq!(get_piece(0, 99), INVALID_COORDINATE);
+        assert_eq!(get_piece(0, 7), 1);
+    }
+
+    #[test]
+    fn last_error_success() {
+        let mut engine = GameEngine::new();
+        assert_eq!(apply_move(&mut engine, 0, 5, 0, 4, &mut NullObserver), 0);
+        assert_eq!(last_error(), "illegal move");
+        let message = unsafe { CStr::from_ptr(last_error_ptr()) };
+        assert_eq!(message.to_str(), Ok("illegal move"));
+
+        assert_eq!(
+            apply_move(&mut engine, -1, 5, 0, 4, &mut NullObserver),
+            INVALID_COORDINATE
+        );
+        assert_eq!(last_error(), "coordinate is off the board");
+
+        assert_eq!(apply_move(&mut engine, 0, 5, 1, 4, &mut NullObserver), 1);
+        assert_eq!(last_error(), "");
+
+        assert_eq!(
+            apply_packed_move(&mut engine, -1, &mut NullObserver),
+            INVALID_COORDINATE
+        );
+        assert_eq!(last_error(), "coordinate is off the board");
+    }
+
+    #[test]
+    fn apply_pass_success() {
+        let mut engine = GameEngine::with_rules(RulesBuilder::new().allow_pass(true).build());
+        assert_eq!(apply_pass(&mut engine, &mut NullObserver), 1);
+        assert_eq!(engine.current_turn(), PieceColor::White);
+        assert_eq!(last_error(), "");
+
+        let mut engine = GameEngine::new();
+        assert_eq!(apply_pass(&mut engine, &mut NullObserver), 0);
+        assert_eq!(last_error(), "passing is not allowed");
+    }
+
+    #[test]
+    fn ply_count_success() {
+        let mut engine = GameEngine::new();
+        assert_eq!(ply_count(&engine), 0);
+        apply_move(&mut engine, 2, 5, 3, 4, &mut NullObserver);
+        apply_move(&mut engine, 1, 2, 2, 3, &mut NullObserver);
+        assert_eq!(ply_count(&engine), 2);
+    }
+
+    #[test]
+    fn fill_board_success() {
+        let engine = GameEngine::new();
+        let mut out = [0; 64];
+        fill_board(&engine, &mut out);
+
+        // white on the first rows, black on the last ones
+        assert_eq!(&out[0..8], &[-1, 2, -1, 2, -1, 2, -1, 2]);
+        assert_eq!(&out[24..40], &[-1; 16]);
+        assert_eq!(&out[56..64], &[1, -1, 1, -1, 1, -1, 1, -1]);
+        assert_eq!(out.iter().filter(|&&cell| cell == 1).count(), 12);
+        assert_eq!(out.iter().filter(|&&cell| cell == 2).count(), 12);
+    }
+
+    #[test]
+    fn apply_packed_move_success() {
+        let mut engine = GameEngine::new();
+        let packed = i32::from(Move::new((0, 5), (1, 4)).pack());
+        assert_eq!(apply_packed_move(&mut engine, packed, &mut NullObserver), 1);
+        assert_eq!(engine.last_move(), Some(Move::new((0, 5), (1, 4))));
+
+        // the same move again is illegal, and values outside 12 bits are rejected
+        assert_eq!(apply_packed_move(&mut engine, packed, &mut NullObserver), 0);
+        assert_eq!(
+            apply_packed_move(&mut engine, -1, &mut NullObserver),
+            INVALID_COORDINATE
+        );
+        assert_eq!(
+            apply_packed_move(&mut engine, 1 << 12, &mut NullObserver),
+            INVALID_COORDINATE
+        );
+    }
+
+    #[test]
+    fn state_json_success() {
+        let engine = GameEngine::new();
+        let state: serde_json::Value = serde_json::from_str(&engine.state_json()).unwrap();
+
+        assert_eq!(state["current_turn"], "black");
+        assert_eq!(state["move_count"], 0);
+        assert!(state["winner"].is_null());
+        assert_eq!(state["board"].as_array().unwrap().len(), 8);
+        assert_eq!(state["board"][0][1], 2);
+        assert_eq!(state["board"][7][0], 1);
+        assert_eq!(state["board"][4][4], -1);
+        assert_eq!(state["legal_moves"].as_array().unwrap().len(), 7);
+        assert_eq!(state["legal_moves"][0], serde_json::json!([0, 5, 1, 4]));
+    }
+}
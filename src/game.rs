@@ -1,14 +1,195 @@
-use super::board::{Coordinate, GamePiece, Move, PieceColor};
+pub use super::board::BOARD_SIZE;
+use super::board::{Coordinate, GamePiece, Move, Orientation, PieceColor, PieceKind};
+use super::rules::Rules;
+pub use super::rules::DRAW_PLY_LIMIT;
+#[cfg(not(feature = "std"))]
+use alloc::string::{String, ToString};
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+#[cfg(not(feature = "std"))]
+use alloc::{format, vec};
+#[cfg(any(test, feature = "test-utils"))]
+use rand::seq::SliceRandom;
+#[cfg(any(test, feature = "test-utils"))]
+use rand::Rng;
+#[cfg(feature = "std")]
+use std::collections::{HashMap, HashSet};
 
+#[derive(Clone)]
 pub struct GameEngine {
-    board: [[Option<GamePiece>; 8]; 8],
+    board: [[Option<GamePiece>; BOARD_SIZE]; BOARD_SIZE],
     current_turn: PieceColor,
     move_count: u32,
+    plies_since_progress: u32,
+    rules: Rules,
+    /// The piece part way through a multi-jump, which must keep capturing.
+    jumping_piece: Option<Coordinate>,
+    /// Pieces jumped during the current multi-jump but left on the board under deferred removal.
+    pending_captures: Vec<Coordinate>,
+    last_move: Option<Move>,
+    /// Each move made, for game review.
+    history: Vec<MoveResult>,
+    /// The state before each move in history, for undo.
+    snapshots: Vec<Snapshot>,
+    /// Off on the copies made for searching, whose moves are never taken back.
+    recording: bool,
+    black_captured: u32,
+    white_captured: u32,
+    /// Set when the game ends off the board, by resignation or an agreed draw.
+    status: GameStatus,
+    draw_offer: Option<PieceColor>,
+    /// How the pieces were set out, so reset_game can set them out the same way.
+    layout: Layout,
+    /// The color which moved first, so reset_game gives it the first move again.
+    first_turn: PieceColor,
 }
 
+/// Layout names the starting arrangements a GameEngine can set out.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Layout {
+    Standard,
+    /// The rows of black and white pieces chosen through with_setup.
+    Rows(u8, u8),
+    Turkish,
+}
+
+/// Snapshot holds the parts of a GameEngine a move changes.
+#[derive(Clone)]
+struct Snapshot {
+    board: [[Option<GamePiece>; BOARD_SIZE]; BOARD_SIZE],
+    current_turn: PieceColor,
+    move_count: u32,
+    plies_since_progress: u32,
+    jumping_piece: Option<Coordinate>,
+    pending_captures: Vec<Coordinate>,
+    last_move: Option<Move>,
+    black_captured: u32,
+    white_captured: u32,
+    status: GameStatus,
+    draw_offer: Option<PieceColor>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
 pub struct MoveResult {
     pub move_made: Move,
+    /// The square of the piece jumped by this move, None for a simple move.
+    pub captured: Option<Coordinate>,
+    /// The square and piece of each piece jumped by this move, for animating their removal.
+    pub captured_pieces: Vec<(Coordinate, GamePiece)>,
     pub crowned: bool,
+    /// The player to move once this move is made, unchanged while a multi-jump continues.
+    pub current_turn: PieceColor,
+}
+
+/// GameObserver trait receives the events of moves made through move_piece_observed and of
+/// turns passed through pass_turn_observed.
+pub trait GameObserver {
+    /// piece_moved method is called after every hop.
+    fn piece_moved(&mut self, _move_made: &Move) {}
+
+    /// piece_captured method is called for each piece a hop jumps, after piece_moved.
+    fn piece_captured(&mut self, _coord: Coordinate, _piece: GamePiece) {}
+
+    /// piece_crowned method is called when a piece is crowned.
+    fn piece_crowned(&mut self, _coord: Coordinate) {}
+
+    /// turn_changed method is called once a move is complete or a turn is passed, and the other
+    /// player is to move.
+    fn turn_changed(&mut self, _new_turn: PieceColor) {}
+}
+
+/// NoObserver ignores every event, for replaying a game nobody is watching.
+struct NoObserver;
+
+impl GameObserver for NoObserver {}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum MoveError {
+    IllegalMove,
+    PassNotAllowed,
+    GameOver,
+    NoDrawOffer,
+    InvalidNotation,
+    OffBoard,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum BoardError {
+    OffBoard,
+    EmptySquare,
+    LightSquare,
+}
+
+/// GameReport is a snapshot of the game for logging and dashboards.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GameReport {
+    pub current_turn: PieceColor,
+    pub move_count: u32,
+    pub black_men: usize,
+    pub black_kings: usize,
+    pub white_men: usize,
+    pub white_kings: usize,
+    /// Whether the side to move must capture this turn.
+    pub capture_forced: bool,
+    pub status: GameStatus,
+    pub legal_move_count: usize,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct JumpState {
+    /// Whether the hop ended the turn.
+    pub complete: bool,
+    /// The squares the jumping piece may hop to next, empty once the turn is complete.
+    pub continuations: Vec<Coordinate>,
+}
+
+/// BoardDelta holds the squares one move changed, for storing games compactly.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BoardDelta {
+    /// Each changed square with the piece it holds after the move.
+    pub changes: Vec<(Coordinate, Option<GamePiece>)>,
+}
+
+/// Which end of the board each color starts from, black at the highest rows.
+pub const ORIENTATION: Orientation = Orientation::BlackHigh;
+
+/// Score advancement_score gives each king, as many rows as a man crosses to be crowned.
+pub const KING_ADVANCEMENT: u32 = BOARD_SIZE as u32 - 1;
+
+/// Score king_safety takes off for each king the opponent could capture next turn.
+pub const KING_THREAT_PENALTY: i32 = 4;
+
+/// Length in bytes of the compact encoding produced by to_bytes.
+pub const ENCODED_LEN: usize = 13;
+
+/// Increment of the splitmix64 sequence, the odd number nearest 2^64 over the golden ratio.
+const SPLITMIX_GAMMA: u64 = 0x9E37_79B9_7F4A_7C15;
+
+/// Length in bytes of the encoding to_bytes produces under orthogonal_moves, covering all 64
+/// squares.
+pub const ENCODED_LEN_ALL_SQUARES: usize = 25;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DecodeError {
+    Truncated,
+    TrailingBytes,
+    InvalidSquare,
+    InvalidTurn,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Outcome {
+    Win(PieceColor),
+    Draw,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum GameStatus {
+    InProgress,
+    Won(PieceColor),
+    Draw,
+    /// The color which resigned.
+    Resigned(PieceColor),
 }
 
 impl GameEngine {
@@ -18,17 +199,242 @@ impl GameEngine {
     ///
     /// Returns the instance of type GameEngine.
     pub fn new() -> GameEngine {
+        GameEngine::with_rules(Rules::default())
+    }
+
+    /// with_rules method creates a new instance of GameEngine playing by the given rules.
+    ///
+    /// #Arguments
+    ///
+    /// rules - a parameter of type Rules which configures move generation.
+    ///
+    /// #Return
+    ///
+    /// Returns the instance of type GameEngine.
+    pub fn with_rules(rules: Rules) -> GameEngine {
         let mut engine = GameEngine {
-            board: [[None; 8]; 8],
+            board: [[None; BOARD_SIZE]; BOARD_SIZE],
             current_turn: PieceColor::Black,
             move_count: 0,
+            plies_since_progress: 0,
+            rules,
+            jumping_piece: None,
+            pending_captures: Vec::new(),
+            last_move: None,
+            history: Vec::new(),
+            snapshots: Vec::new(),
+            recording: true,
+            black_captured: 0,
+            white_captured: 0,
+            status: GameStatus::InProgress,
+            draw_offer: None,
+            layout: Layout::Standard,
+            first_turn: PieceColor::Black,
         };
         engine.initialize_pieces();
         engine
     }
 
+    /// new_game_loser_starts method creates a rematch in which the previous loser moves first.
+    ///
+    /// #Arguments
+    ///
+    /// previous_winner - a parameter of type PieceColor denoting the winner of the last game.
+    ///
+    /// #Return
+    ///
+    /// Returns the instance of type GameEngine with the other color to move.
+    pub fn new_game_loser_starts(previous_winner: PieceColor) -> GameEngine {
+        let mut engine = GameEngine::new();
+        engine.current_turn = previous_winner.opponent();
+        engine.first_turn = engine.current_turn;
+        engine
+    }
+
+    /// with_setup method creates a new instance of GameEngine with a custom number of rows.
+    ///
+    /// Each side fills the dark squares of its rows nearest its own edge, so new() is the
+    /// same as three rows each.
+    ///
+    /// #Arguments
+    ///
+    /// black_rows - an u8 parameter for the number of rows of black pieces.
+    /// white_rows - an u8 parameter for the number of rows of white pieces.
+    ///
+    /// #Return
+    ///
+    /// Returns the instance of type GameEngine wrapped in Option, None if the rows overlap.
+    pub fn with_setup(black_rows: u8, white_rows: u8) -> Option<GameEngine> {
+        if black_rows as usize + white_rows as usize > BOARD_SIZE {
+            return None;
+        }
+        let mut engine = GameEngine::new();
+        engine.initialize_rows(black_rows, white_rows);
+        Some(engine)
+    }
+
+    /// from_pieces method creates a new instance of GameEngine holding only the listed pieces.
+    ///
+    /// #Arguments
+    ///
+    /// pieces - a slice of Coordinate and GamePiece pairs to be placed.
+    /// turn - a parameter of type PieceColor denoting the side to move.
+    ///
+    /// #Return
+    ///
+    /// Returns the instance of type GameEngine, or a BoardError if a piece is off the board or
+    /// on a light square.
+    pub fn from_pieces(
+        pieces: &[(Coordinate, GamePiece)],
+        turn: PieceColor,
+    ) -> Result<GameEngine, BoardError> {
+        GameEngine::from_pieces_with_rules(pieces, turn, Rules::default())
+    }
+
+    /// from_pieces_with_rules method creates a new instance of GameEngine holding only the listed
+    /// pieces and playing by the given rules.
+    ///
+    /// #Arguments
+    ///
+    /// pieces - a slice of Coordinate and GamePiece pairs to be placed.
+    /// turn - a parameter of type PieceColor denoting the side to move.
+    /// rules - a parameter of type Rules which configures move generation.
+    ///
+    /// #Return
+    ///
+    /// Returns the instance of type GameEngine, or a BoardError if a piece is off the board or
+    /// on a light square.
+    pub fn from_pieces_with_rules(
+        pieces: &[(Coordinate, GamePiece)],
+        turn: PieceColor,
+        rules: Rules,
+    ) -> Result<GameEngine, BoardError> {
+        let mut engine = GameEngine::with_rules(rules);
+        engine.board = [[None; BOARD_SIZE]; BOARD_SIZE];
+        engine.current_turn = turn;
+        for &(coord, piece) in pieces {
+            if !coord.on_board() {
+                return Err(BoardError::OffBoard);
+            }
+            let Coordinate(x, y) = coord;
+            if (x + y) % 2 == 0 {
+                return Err(BoardError::LightSquare);
+            }
+            engine.board[x][y] = Some(piece);
+        }
+        Ok(engine)
+    }
+
+    /// random_position method scatters a random set of men and kings over the dark squares.
+    ///
+    /// Each side gets between 1 and 12 pieces and men are never left on the row where they
+    /// would be crowned, so every generated position could arise in a game.
+    ///
+    /// #Arguments
+    ///
+    /// rng - a random number generator implementing Rng.
+    ///
+    /// #Return
+    ///
+    /// Returns the instance of type GameEngine with a random side to move.
+    #[cfg(any(test, feature = "test-utils"))]
+    pub fn random_position(rng: &mut impl Rng) -> GameEngine {
+        let mut engine = GameEngine::new();
+        engine.board = [[None; BOARD_SIZE]; BOARD_SIZE];
+        engine.current_turn = if rng.gen_bool(0.5) {
+            PieceColor::Black
+        } else {
+            PieceColor::White
+        };
+
+        let mut squares = dark_squares().collect::<Vec<Coordinate>>();
+        squares.shuffle(rng);
+        let black_count = rng.gen_range(1..=12);
+        let white_count = rng.gen_range(1..=12);
+        for (index, coord) in squares
+            .into_iter()
+            .take(black_count + white_count)
+            .enumerate()
+        {
+            let color = if index < black_count {
+                PieceColor::Black
+            } else {
+                PieceColor::White
+            };
+            let mut piece = GamePiece::new(color);
+            if rng.gen_bool(0.25) || engine.should_crown(piece, coord) {
+                piece = GamePiece::crowned(piece);
+            }
+            let Coordinate(x, y) = coord;
+            engine.board[x][y] = Some(piece);
+        }
+        engine
+    }
+
+    /// replay_to_ply method replays the start of a game from the opening position.
+    ///
+    /// #Arguments
+    ///
+    /// moves - a slice of Move holding the game, one entry per hop.
+    /// ply - an usize parameter for the number of moves to replay.
+    ///
+    /// #Return
+    ///
+    /// Returns the GameEngine after ply moves, or the MoveError of the first failing move,
+    /// MoveError::IllegalMove if ply is past the end of moves.
+    pub fn replay_to_ply(moves: &[Move], ply: usize) -> Result<GameEngine, MoveError> {
+        let moves = moves.get(..ply).ok_or(MoveError::IllegalMove)?;
+        GameEngine::replay_with(moves, &mut NoObserver)
+    }
+
+    /// replay_with method replays a game from the opening position, reporting every event.
+    ///
+    /// #Arguments
+    ///
+    /// moves - a slice of Move holding the game, one entry per hop.
+    /// observer - a GameObserver which receives the events of each move in turn.
+    ///
+    /// #Return
+    ///
+    /// Returns the GameEngine after every move, or the MoveError of the first failing move.
+    pub fn replay_with(
+        moves: &[Move],
+        observer: &mut impl GameObserver,
+    ) -> Result<GameEngine, MoveError> {
+        let mut engine = GameEngine::new();
+        for move_desired in moves {
+            engine.move_piece_observed(move_desired, observer)?;
+        }
+        Ok(engine)
+    }
+
+    /// reset_game method starts a new game on the same engine, keeping the rules it was built with
+    /// and setting the pieces out as they were at the start, with the same color to move first.
+    pub fn reset_game(&mut self) {
+        let layout = self.layout;
+        let first_turn = self.first_turn;
+        *self = GameEngine::with_rules(self.rules);
+        self.current_turn = first_turn;
+        self.first_turn = first_turn;
+        match layout {
+            Layout::Standard => {}
+            Layout::Rows(black_rows, white_rows) => self.initialize_rows(black_rows, white_rows),
+            Layout::Turkish => self.initialize_turkish_pieces(),
+        }
+    }
+
+    /// rules method tells the rules the game is played by.
+    ///
+    /// #Return
+    ///
+    /// Returns the Rules of the game.
+    pub fn rules(&self) -> Rules {
+        self.rules
+    }
+
     /// initialize_pieces method initialises the pieces on the board.
     pub fn initialize_pieces(&mut self) {
+        self.layout = Layout::Standard;
         [1, 3, 5, 7, 0, 2, 4, 6, 1, 3, 5, 7]
             .iter()
             .zip([0, 0, 0, 0, 1, 1, 1, 1, 2, 2, 2, 2].iter())
@@ -46,6 +452,40 @@ impl GameEngine {
             });
     }
 
+    /// initialize_turkish_pieces method sets up the board for Turkish draughts.
+    ///
+    /// Each side fills the second and third rows from its end of the board, sixteen pieces on
+    /// squares of both colors. Play it with the orthogonal_moves rule; flying kings are not
+    /// supported along ranks and files yet.
+    pub fn initialize_turkish_pieces(&mut self) {
+        self.layout = Layout::Turkish;
+        self.board = [[None; BOARD_SIZE]; BOARD_SIZE];
+        for x_coord in 0..8 {
+            for y_coord in 1..3 {
+                self.board[x_coord][y_coord] = Some(GamePiece::new(PieceColor::White));
+                self.board[x_coord][7 - y_coord] = Some(GamePiece::new(PieceColor::Black));
+            }
+        }
+    }
+
+    /// initialize_rows method fills the dark squares of each side's rows nearest its own edge.
+    ///
+    /// #Arguments
+    ///
+    /// black_rows - an u8 parameter for the number of rows of black pieces.
+    /// white_rows - an u8 parameter for the number of rows of white pieces.
+    fn initialize_rows(&mut self, black_rows: u8, white_rows: u8) {
+        self.layout = Layout::Rows(black_rows, white_rows);
+        self.board = [[None; BOARD_SIZE]; BOARD_SIZE];
+        for Coordinate(x, y) in dark_squares() {
+            if y < white_rows as usize {
+                self.board[x][y] = Some(GamePiece::new(PieceColor::White));
+            } else if y >= BOARD_SIZE - black_rows as usize {
+                self.board[x][y] = Some(GamePiece::new(PieceColor::Black));
+            }
+        }
+    }
+
     /// move_piece method make the move desired by user.
     ///
     /// #Arguments
@@ -54,597 +494,4944 @@ impl GameEngine {
     ///
     /// #Return
     ///
-    /// Returns the instance of type MoveResult denoting the result.
-    pub fn move_piece(&mut self, move_desired: &Move) -> Result<MoveResult, ()> {
+    /// Returns the instance of type MoveResult denoting the result, MoveError::OffBoard if either
+    /// square is off the board, or MoveError::GameOver once the game has finished.
+    pub fn move_piece(&mut self, move_desired: &Move) -> Result<MoveResult, MoveError> {
+        if !move_desired.from.on_board() || !move_desired.to.on_board() {
+            return Err(MoveError::OffBoard);
+        }
+        #[cfg(feature = "std")]
+        let legal_moves: HashSet<Move> = self.legal_moves().into_iter().collect();
+        #[cfg(not(feature = "std"))]
         let legal_moves = self.legal_moves();
 
+        if self.status != GameStatus::InProgress || legal_moves.is_empty() || self.is_drawn() {
+            return Err(MoveError::GameOver);
+        }
         if !legal_moves.contains(move_desired) {
-            return Err(());
+            return Err(MoveError::IllegalMove);
         }
 
+        let snapshot = if self.recording {
+            Some(self.snapshot())
+        } else {
+            None
+        };
         let Coordinate(from_x, from_y) = move_desired.from;
-        let Coordinate(to_x, to_y) = move_desired.to;
+        let Coordinate(_, to_y) = move_desired.to;
         let piece = self.board[from_x][from_y].unwrap();
-        let midpiece_coordinate = self.midpiece_coordinate(from_x, from_y, to_x, to_y);
-        if let Some(Coordinate(x, y)) = midpiece_coordinate {
-            self.board[x][y] = None; // remove the jumped piece
+        debug_assert!(
+            piece.crowned
+                || self.is_jump(move_desired)
+                || is_forward(piece.color, from_y, to_y)
+                || (self.rules.orthogonal_moves && from_y == to_y),
+            "uncrowned piece accepted stepping backward"
+        );
+        let (captured, promotes) = self.apply_hop(move_desired);
+        // promotion is settled once, on the square where the move ends
+        let continues = self.rules.multi_jump
+            && captured.is_some()
+            && !(promotes && self.rules.crowning_ends_capture)
+            && self.has_jump_from(move_desired.to);
+        let crowned = promotes && !continues;
+        if crowned {
+            self.crown_piece(move_desired.to);
         }
+        self.last_move = match (self.jumping_piece, self.last_move) {
+            // continuing a multi-jump keeps the start of the whole path
+            (Some(_), Some(last_move)) => Some(Move {
+                from: last_move.from,
+                to: move_desired.to,
+            }),
+            _ => Some(*move_desired),
+        };
 
-        // Move piece from source to destination
-        self.board[to_x][to_y] = Some(piece);
-        self.board[from_x][from_y] = None;
+        if captured.is_some() {
+            match piece.color {
+                PieceColor::Black => self.white_captured += 1,
+                PieceColor::White => self.black_captured += 1,
+            }
+        }
+        if captured.is_some() || crowned {
+            self.plies_since_progress = 0;
+        } else {
+            self.plies_since_progress += 1;
+        }
 
-        let crowned = if self.should_crown(piece, move_desired.to) {
-            self.crown_piece(move_desired.to);
-            true
+        // a capturing piece keeps the turn while it can jump again
+        if continues {
+            self.jumping_piece = Some(move_desired.to);
         } else {
-            false
-        };
-        self.advance_turn();
+            self.jumping_piece = None;
+            for Coordinate(x, y) in self.pending_captures.drain(..) {
+                self.board[x][y] = None;
+            }
+            self.advance_turn();
+        }
 
-        Ok(MoveResult {
-            move_made: move_desired.clone(),
+        self.draw_offer = None;
+        let result = MoveResult {
+            move_made: *move_desired,
+            captured: captured.map(|(coord, _)| coord),
+            captured_pieces: captured.into_iter().collect(),
             crowned,
-        })
+            current_turn: self.current_turn,
+        };
+        if let Some(before) = snapshot {
+            self.history.push(result.clone());
+            self.snapshots.push(before);
+        }
+        Ok(result)
     }
 
-    /// get_piece method gives the piece from a given location on the board.
+    /// apply_partial_jump method makes one hop of a capture and tells where the piece may hop next.
     ///
     /// #Arguments
     ///
-    /// coord - a object of type Coordinate denoting the location to be fetched.
+    /// hop - a parameter of type Move for a single jump.
     ///
     /// #Return
     ///
-    /// Returns the Result type value containing the GamePiece wrapped in Option enum.
-    pub fn get_piece(&self, coord: Coordinate) -> Result<Option<GamePiece>, ()> {
-        let Coordinate(coord_x, coord_y) = coord;
-        if coord_x <= 7 && coord_y <= 7 {
-            Ok(self.board[coord_x][coord_y])
-        } else {
-            Err(())
+    /// Returns the instance of type JumpState, MoveError::OffBoard if either square is off the
+    /// board, or MoveError::IllegalMove if hop isn't a legal jump.
+    pub fn apply_partial_jump(&mut self, hop: Move) -> Result<JumpState, MoveError> {
+        if !hop.from.on_board() || !hop.to.on_board() {
+            return Err(MoveError::OffBoard);
+        }
+        if !self.is_jump(&hop) {
+            return Err(MoveError::IllegalMove);
         }
+        self.move_piece(&hop)?;
+        let continuations = match self.jumping_piece {
+            Some(_) => self.legal_moves().into_iter().map(|m| m.to).collect(),
+            None => Vec::new(),
+        };
+        Ok(JumpState {
+            complete: self.jumping_piece.is_none(),
+            continuations,
+        })
     }
 
-    /// current_turn method tells the player who has current turn.
+    /// undo method takes back the last move, one hop of a multi-jump at a time.
+    ///
+    /// A game ended by resignation or an agreed draw stays over, and edits made through the
+    /// position editor methods can't be taken back.
     ///
     /// #Return
     ///
-    /// Returns an PieceColor enum containing the color of current player's pieces.
-    pub fn current_turn(&self) -> PieceColor {
-        self.current_turn
+    /// Returns the MoveResult of the move taken back wrapped in Option, None if no move was made
+    /// since the last edit or the game was ended off the board.
+    pub fn undo(&mut self) -> Option<MoveResult> {
+        if self.status != GameStatus::InProgress {
+            return None;
+        }
+        let snapshot = self.snapshots.pop()?;
+        let result = self.history.pop()?;
+        self.board = snapshot.board;
+        self.current_turn = snapshot.current_turn;
+        self.move_count = snapshot.move_count;
+        self.plies_since_progress = snapshot.plies_since_progress;
+        self.jumping_piece = snapshot.jumping_piece;
+        self.pending_captures = snapshot.pending_captures;
+        self.last_move = snapshot.last_move;
+        self.black_captured = snapshot.black_captured;
+        self.white_captured = snapshot.white_captured;
+        self.status = snapshot.status;
+        self.draw_offer = snapshot.draw_offer;
+        Some(result)
     }
 
-    /// advance_turn method toggles the current turn of players.
-    fn advance_turn(&mut self) {
-        if self.current_turn == PieceColor::Black {
-            self.current_turn = PieceColor::White
-        } else {
-            self.current_turn = PieceColor::Black
-        }
-        self.move_count += 1;
+    /// forget_history method drops the recorded moves once the position is edited, since they
+    /// no longer lead to it.
+    fn forget_history(&mut self) {
+        self.history.clear();
+        self.snapshots.clear();
     }
 
-    /// should_crown method checks if the piece should crown or not.
-    ///
-    /// #Arguments
-    ///
-    /// piece - A GamePiece type object that is to be checked.
-    /// coord - A Coordinate type object specifying the location of piece.
+    /// snapshot method copies the state a move changes, for undo.
     ///
     /// #Return
     ///
-    /// Returns bool value denoting if the piece should crown.
-    fn should_crown(&self, piece: GamePiece, coord: Coordinate) -> bool {
-        let Coordinate(_coord_x, coord_y) = coord;
-
-        (coord_y == 0 && piece.color == PieceColor::Black)
-            || (coord_y == 7 && piece.color == PieceColor::White)
+    /// Returns the instance of type Snapshot.
+    fn snapshot(&self) -> Snapshot {
+        Snapshot {
+            board: self.board,
+            current_turn: self.current_turn,
+            move_count: self.move_count,
+            plies_since_progress: self.plies_since_progress,
+            jumping_piece: self.jumping_piece,
+            pending_captures: self.pending_captures.clone(),
+            last_move: self.last_move,
+            black_captured: self.black_captured,
+            white_captured: self.white_captured,
+            status: self.status,
+            draw_offer: self.draw_offer,
+        }
     }
 
-    /// crown_piece method crowns a given piece on the board.
+    /// search_copy method copies the position without the game record, for looking ahead.
     ///
-    /// #Arguments
-    ///
-    /// coord - A Coordinate type object denoting the location whose piece id to be crowned.
+    /// Moves made on the copy aren't recorded, so copying costs the same however long the game
+    /// has run. The copy has nothing to undo.
     ///
     /// #Return
     ///
-    /// Returns a bool value telling if the piece is crowned succesfully.
-    fn crown_piece(&mut self, coord: Coordinate) -> bool {
-        let Coordinate(coord_x, coord_y) = coord;
-        if let Some(piece) = self.board[coord_x][coord_y] {
-            self.board[coord_x][coord_y] = Some(GamePiece::crowned(piece));
-            true
-        } else {
-            false
+    /// Returns the instance of type GameEngine.
+    pub(crate) fn search_copy(&self) -> GameEngine {
+        GameEngine {
+            board: self.board,
+            current_turn: self.current_turn,
+            move_count: self.move_count,
+            plies_since_progress: self.plies_since_progress,
+            rules: self.rules,
+            jumping_piece: self.jumping_piece,
+            pending_captures: self.pending_captures.clone(),
+            last_move: self.last_move,
+            history: Vec::new(),
+            snapshots: Vec::new(),
+            recording: false,
+            black_captured: self.black_captured,
+            white_captured: self.white_captured,
+            status: self.status,
+            draw_offer: self.draw_offer,
+            layout: self.layout,
+            first_turn: self.first_turn,
         }
     }
 
-    /// is_crowned method checks if the piece is crowned or not.
+    /// move_piece_observed method makes the move desired by user and reports it to an observer.
+    ///
+    /// turn_changed fires once per completed move, so only after the last hop of a multi-jump.
     ///
     /// #Arguments
     ///
-    /// coord - A Coordinate type object denoting location of piece to be checked.
+    /// move_desired - a reference of type Move which holds the move to be made.
+    /// observer - a GameObserver which receives the events of the move.
     ///
     /// #Return
     ///
-    /// Returns a bool value denoting if the piece is crowned or not.
-    pub fn is_crowned(&self, coord: Coordinate) -> bool {
-        let Coordinate(coord_x, coord_y) = coord;
-        match self.board[coord_x][coord_y] {
-            Some(piece) => piece.crowned,
-            None => false,
+    /// Returns the instance of type MoveResult denoting the result.
+    pub fn move_piece_observed(
+        &mut self,
+        move_desired: &Move,
+        observer: &mut dyn GameObserver,
+    ) -> Result<MoveResult, MoveError> {
+        let turn = self.current_turn;
+        let result = self.move_piece(move_desired)?;
+        observer.piece_moved(&result.move_made);
+        for &(coord, piece) in &result.captured_pieces {
+            observer.piece_captured(coord, piece);
+        }
+        if result.crowned {
+            observer.piece_crowned(result.move_made.to);
         }
+        if self.current_turn != turn {
+            observer.turn_changed(self.current_turn);
+        }
+        Ok(result)
     }
 
-    /// move_count method tells the number of moves made.
+    /// try_move method makes the move between two squares if it is legal.
+    ///
+    /// #Arguments
+    ///
+    /// from - a tuple of (usize, usize) holding the square of the moving piece.
+    /// to - a tuple of (usize, usize) holding the destination square.
     ///
     /// #Return
     ///
-    /// Returns an u32 value denoting the count of moves.
-    pub fn move_count(&self) -> u32 {
-        self.move_count
+    /// Returns a bool value denoting whether the move was legal and made.
+    pub fn try_move(&mut self, from: (usize, usize), to: (usize, usize)) -> bool {
+        self.move_piece(&Move::new(from, to)).is_ok()
     }
 
-    /// legal_moves method gives all the legal moves for all locations on the board.
+    /// apply_moves method makes a sequence of moves, all or nothing.
+    ///
+    /// #Arguments
+    ///
+    /// moves - a slice of Move to be made in order.
     ///
     /// #Return
     ///
-    /// Returns vector containing the legal moves.
-    fn legal_moves(&self) -> Vec<Move> {
-        let mut moves: Vec<Move> = Vec::new();
-        for col in 0..8 {
-            for row in 0..8 {
-                if let Some(piece) = self.board[col][row] {
-                    if piece.color == self.current_turn {
-                        let loc = Coordinate(col, row);
-                        let mut vmoves = self.valid_moves_from(loc);
-                        moves.append(&mut vmoves);
-                    }
+    /// Returns the vector of MoveResult, or the index and MoveError of the first failing move
+    /// with the engine restored to its state before the call.
+    pub fn apply_moves(&mut self, moves: &[Move]) -> Result<Vec<MoveResult>, (usize, MoveError)> {
+        let backup = self.clone();
+        let mut results = Vec::new();
+        for (index, move_desired) in moves.iter().enumerate() {
+            match self.move_piece(move_desired) {
+                Ok(result) => results.push(result),
+                Err(err) => {
+                    *self = backup;
+                    return Err((index, err));
                 }
             }
         }
-
-        moves
+        Ok(results)
     }
 
-    /// valid_moves_from method gives all the valid moves from a particular location on the board.
+    /// move_path method makes a whole turn given as the squares a piece visits, all or nothing.
+    ///
+    /// Every hop is checked against the legal moves as it is reached, so a path that jumps an
+    /// empty square, jumps a friendly piece or lands on an occupied square is rejected, as is
+    /// a path that stops before a multi-jump is complete.
     ///
     /// #Arguments
     ///
-    /// loc - A Coordinate type object denoting location of piece whose valid moves are desired.
+    /// path - a slice of Coordinate starting at the moving piece, one entry per landing square.
     ///
     /// #Return
     ///
-    /// Returns the vector of valid moves.
-    fn valid_moves_from(&self, loc: Coordinate) -> Vec<Move> {
-        let Coordinate(x, y) = loc;
-        if let Some(piece) = self.board[x][y] {
-            let mut jumps = loc
-                .jump_targets_from()
-                .filter(|coord| self.valid_jump(&piece, &loc, &coord))
-                .map(|ref coord| Move {
-                    from: loc.clone(),
-                    to: coord.clone(),
-                })
-                .collect::<Vec<Move>>();
-            let mut moves = loc
-                .move_targets_from()
-                .filter(|coord| self.valid_move(&piece, &loc, &coord))
-                .map(|ref coord| Move {
-                    from: loc.clone(),
-                    to: coord.clone(),
-                })
-                .collect::<Vec<Move>>();
-            jumps.append(&mut moves);
-            jumps
-        } else {
-            Vec::new()
+    /// Returns the vector of MoveResult, one per hop, or the MoveError with the engine unchanged.
+    pub fn move_path(&mut self, path: &[Coordinate]) -> Result<Vec<MoveResult>, MoveError> {
+        if path.len() < 2 {
+            return Err(MoveError::IllegalMove);
+        }
+
+        let mut trial = self.clone();
+        let mut results = Vec::new();
+        for hop in path.windows(2) {
+            let move_desired = Move {
+                from: hop[0],
+                to: hop[1],
+            };
+            results.push(trial.move_piece(&move_desired)?);
+        }
+        if trial.jumping_piece.is_some() {
+            return Err(MoveError::IllegalMove);
         }
+
+        *self = trial;
+        Ok(results)
     }
 
-    /// midpiece_coordinate method gives the location of piece in between the jump.
+    /// apply_hop method moves a piece one hop on the board, removing any jumped piece.
+    ///
+    /// The piece is not crowned here, as that depends on whether the move goes on.
     ///
     /// #Arguments
     ///
-    /// from_x - an usize parameter for x coordinate of starting location.
-    /// from_y - an usize parameter for y coordinate of starting location.
-    /// to_x - an usize parameter for x coordinate of final location.
-    /// to_y - an usize parameter for y coordinate of final location.
+    /// move_desired - a reference of type Move which holds the hop to be made.
     ///
     /// #Return
     ///
-    /// Returns the Coordinate of mid piece wrapped in Option.
-    fn midpiece_coordinate(
-        &self,
-        from_x: usize,
-        from_y: usize,
-        to_x: usize,
-        to_y: usize,
-    ) -> Option<Coordinate> {
-        if to_x == from_x + 2 && to_y == from_y + 2 {
-            Some(Coordinate(from_x + 1, from_y + 1))
-        } else if from_x >= 2 && from_y >= 2 && to_x == from_x - 2 && to_y == from_y - 2 {
-            Some(Coordinate(from_x - 1, from_y - 1))
-        } else if from_x >= 2 && to_x == from_x - 2 && to_y == from_y + 2 {
-            Some(Coordinate(from_x - 1, from_y + 1))
-        } else if from_y >= 2 && to_x == from_x + 2 && to_y == from_y - 2 {
-            Some(Coordinate(from_x + 1, from_y - 1))
+    /// Returns a tuple of the captured Coordinate and GamePiece wrapped in Option and whether an
+    /// uncrowned piece reached its crowning row.
+    fn apply_hop(&mut self, move_desired: &Move) -> (Option<(Coordinate, GamePiece)>, bool) {
+        let Coordinate(from_x, from_y) = move_desired.from;
+        let Coordinate(to_x, to_y) = move_desired.to;
+        let piece = self.board[from_x][from_y].unwrap();
+        let captured = self
+            .midpiece_coordinate(from_x, from_y, to_x, to_y)
+            .map(|coord| {
+                let Coordinate(x, y) = coord;
+                (coord, self.board[x][y].unwrap())
+            });
+        if let Some((coord, _)) = captured {
+            if self.rules.deferred_capture_removal {
+                self.pending_captures.push(coord);
+            } else {
+                let Coordinate(x, y) = coord;
+                self.board[x][y] = None; // remove the jumped piece
+            }
+        }
+
+        // Move piece from source to destination
+        self.board[to_x][to_y] = Some(piece);
+        self.board[from_x][from_y] = None;
+
+        let promotes = !piece.crowned && self.should_crown(piece, move_desired.to);
+        (captured, promotes)
+    }
+
+    /// move_notation method makes a whole turn written in standard checkers notation.
+    ///
+    /// Squares are numbered 1 to 32 and joined by '-' for a step, as in "11-15", or by 'x' for
+    /// captures, as in "15x22" or "1x10x19" for a multi-jump.
+    ///
+    /// #Arguments
+    ///
+    /// notation - a str reference holding the move.
+    ///
+    /// #Return
+    ///
+    /// Returns the MoveResult of the last hop, MoveError::InvalidNotation if the text can't be
+    /// read, or the MoveError of the move with the engine unchanged.
+    pub fn move_notation(&mut self, notation: &str) -> Result<MoveResult, MoveError> {
+        let notation = notation.trim();
+        let capture = notation.contains('x');
+        let separator = if capture { 'x' } else { '-' };
+        let path = notation
+            .split(separator)
+            .map(|square| {
+                square
+                    .parse::<u8>()
+                    .ok()
+                    .and_then(|number| Coordinate::from_square_number(number, BOARD_SIZE))
+            })
+            .collect::<Option<Vec<Coordinate>>>()
+            .ok_or(MoveError::InvalidNotation)?;
+        if path.len() < 2 || (!capture && path.len() != 2) {
+            return Err(MoveError::InvalidNotation);
+        }
+        if capture
+            != self.is_jump(&Move {
+                from: path[0],
+                to: path[1],
+            })
+        {
+            return Err(MoveError::IllegalMove);
+        }
+        let mut results = self.move_path(&path)?;
+        Ok(results.pop().unwrap())
+    }
+
+    /// is_legal_prefix method checks if the squares dragged over so far start a legal move.
+    ///
+    /// #Arguments
+    ///
+    /// path - a slice of Coordinate starting at the moving piece, one entry per landing square.
+    ///
+    /// #Return
+    ///
+    /// Returns a bool value, true when every hop so far is legal and each hop but the last
+    /// leaves the same piece still jumping.
+    pub fn is_legal_prefix(&self, path: &[Coordinate]) -> bool {
+        let first = match path.first() {
+            Some(&first) => first,
+            None => return false,
+        };
+        if !first.on_board() || self.legal_moves().iter().all(|m| m.from != first) {
+            return false;
+        }
+        let mut engine = self.search_copy();
+        for (index, hop) in path.windows(2).enumerate() {
+            if index > 0 && engine.jumping_piece != Some(hop[0]) {
+                return false;
+            }
+            let move_desired = Move {
+                from: hop[0],
+                to: hop[1],
+            };
+            if engine.move_piece(&move_desired).is_err() {
+                return false;
+            }
+        }
+        true
+    }
+
+    /// get_piece method gives the piece from a given location on the board.
+    ///
+    /// #Arguments
+    ///
+    /// coord - a object of type Coordinate denoting the location to be fetched.
+    ///
+    /// #Return
+    ///
+    /// Returns the Result type value containing the GamePiece wrapped in Option enum.
+    #[allow(clippy::result_unit_err)]
+    pub fn get_piece(&self, coord: Coordinate) -> Result<Option<GamePiece>, ()> {
+        let Coordinate(coord_x, coord_y) = coord;
+        if coord_x < BOARD_SIZE && coord_y < BOARD_SIZE {
+            Ok(self.board[coord_x][coord_y])
         } else {
-            None
+            Err(())
         }
     }
 
-    /// midpiece method gives the piece in between the jump.
+    /// piece_at method gives the piece at a location assumed to be on the board.
     ///
     /// #Arguments
     ///
-    /// from_x - an usize parameter for x coordinate of starting location.
-    /// from_y - an usize parameter for y coordinate of starting location.
-    /// to_x - an usize parameter for x coordinate of final location.
-    /// to_y - an usize parameter for y coordinate of final location.
+    /// coord - a object of type Coordinate denoting the location to be fetched.
     ///
     /// #Return
     ///
-    /// Returns the GamePiece in middle wrapped in Option.
-    fn midpiece(
-        &self,
-        from_x: usize,
-        from_y: usize,
-        to_x: usize,
-        to_y: usize,
-    ) -> Option<GamePiece> {
-        match self.midpiece_coordinate(from_x, from_y, to_x, to_y) {
-            Some(Coordinate(coord_x, coord_y)) => self.board[coord_x][coord_y],
-            None => None,
+    /// Returns the GamePiece at the location wrapped in Option, None for an empty square.
+    pub fn piece_at(&self, coord: Coordinate) -> Option<GamePiece> {
+        let Coordinate(coord_x, coord_y) = coord;
+        self.board[coord_x][coord_y]
+    }
+
+    /// piece_at_square method gives the piece on a numbered square, for notation-based input.
+    ///
+    /// #Arguments
+    ///
+    /// n - an u8 parameter for the square number, from 1 to 32.
+    ///
+    /// #Return
+    ///
+    /// Returns the GamePiece on the square wrapped in Option, None for an empty square or a
+    /// number out of range.
+    pub fn piece_at_square(&self, n: u8) -> Option<GamePiece> {
+        Coordinate::from_square_number(n, BOARD_SIZE).and_then(|coord| self.piece_at(coord))
+    }
+
+    /// current_turn method tells the player who has current turn.
+    ///
+    /// #Return
+    ///
+    /// Returns an PieceColor enum containing the color of current player's pieces.
+    pub fn current_turn(&self) -> PieceColor {
+        self.current_turn
+    }
+
+    /// pass_turn method hands the turn to the other player without moving.
+    ///
+    /// A pass counts as a ply without progress toward the rules' draw ply limit.
+    ///
+    /// #Return
+    ///
+    /// Returns a Result which is an error when the rules don't allow passing or a multi-jump
+    /// is unfinished.
+    pub fn pass_turn(&mut self) -> Result<(), MoveError> {
+        if self.status() != GameStatus::InProgress {
+            return Err(MoveError::GameOver);
+        }
+        if !self.rules.allow_pass {
+            return Err(MoveError::PassNotAllowed);
         }
+        if self.jumping_piece.is_some() {
+            return Err(MoveError::IllegalMove);
+        }
+        self.plies_since_progress += 1;
+        self.advance_turn();
+        Ok(())
     }
 
-    /// valid_jump method check if the jump is valid from a location on board to other.
+    /// pass_turn_observed method hands the turn to the other player and reports it to an observer.
     ///
     /// #Arguments
     ///
-    /// moving_piece - A GamePiece type reference for the Piece making the jump.
-    /// from - A Coordinate type reference denoting starting location.
-    /// to - A Coordinate type reference denoting final location.
+    /// observer - a GameObserver which is told about the turn change.
     ///
     /// #Return
     ///
-    /// Returns a bool value telling if the jump is valid.
-    fn valid_jump(&self, moving_piece: &GamePiece, from: &Coordinate, to: &Coordinate) -> bool {
-        if !to.on_board() || !from.on_board() {
-            false
+    /// Returns a Result which is an error, with nothing reported, when the turn can't be passed.
+    pub fn pass_turn_observed(&mut self, observer: &mut dyn GameObserver) -> Result<(), MoveError> {
+        self.pass_turn()?;
+        observer.turn_changed(self.current_turn);
+        Ok(())
+    }
+
+    /// advance_turn method toggles the current turn of players.
+    fn advance_turn(&mut self) {
+        if self.current_turn == PieceColor::Black {
+            self.current_turn = PieceColor::White
         } else {
-            let Coordinate(from_x, from_y) = *from;
-            let Coordinate(to_x, to_y) = *to;
+            self.current_turn = PieceColor::Black
+        }
+        self.move_count += 1;
+    }
 
-            let midpiece = self.midpiece(from_x, from_y, to_x, to_y);
-            match midpiece {
-                Some(piece) if piece.color != moving_piece.color => true,
-                _ => false,
+    /// should_crown method checks if the piece should crown or not.
+    ///
+    /// #Arguments
+    ///
+    /// piece - A GamePiece type object that is to be checked.
+    /// coord - A Coordinate type object specifying the location of piece.
+    ///
+    /// #Return
+    ///
+    /// Returns bool value denoting if the piece should crown.
+    fn should_crown(&self, piece: GamePiece, coord: Coordinate) -> bool {
+        let Coordinate(_coord_x, coord_y) = coord;
+
+        coord_y == piece.color.crown_row(BOARD_SIZE, ORIENTATION)
+    }
+
+    /// crown_piece method crowns a given piece on the board.
+    ///
+    /// #Arguments
+    ///
+    /// coord - A Coordinate type object denoting the location whose piece id to be crowned.
+    ///
+    /// #Return
+    ///
+    /// Returns a bool value telling if the piece is crowned succesfully.
+    fn crown_piece(&mut self, coord: Coordinate) -> bool {
+        let Coordinate(coord_x, coord_y) = coord;
+        if let Some(piece) = self.board[coord_x][coord_y] {
+            self.board[coord_x][coord_y] = Some(GamePiece::crowned(piece));
+            true
+        } else {
+            false
+        }
+    }
+
+    /// set_crowned method crowns or uncrowns the piece at a location, for position editors.
+    ///
+    /// #Arguments
+    ///
+    /// coord - A Coordinate type object denoting location of the piece to be changed.
+    /// crowned - a bool value, true to make the piece a king and false to make it a man.
+    ///
+    /// #Return
+    ///
+    /// Returns the unit type, or a BoardError if coord is off the board or holds no piece.
+    pub fn set_crowned(&mut self, coord: Coordinate, crowned: bool) -> Result<(), BoardError> {
+        if !coord.on_board() {
+            return Err(BoardError::OffBoard);
+        }
+        let Coordinate(coord_x, coord_y) = coord;
+        match self.board[coord_x][coord_y].as_mut() {
+            Some(piece) => {
+                piece.crowned = crowned;
+                self.forget_history();
+                Ok(())
             }
+            None => Err(BoardError::EmptySquare),
         }
     }
 
-    /// valid_move method check if the move is valid from a location on board to other.
+    /// is_crowned method checks if the piece is crowned or not.
     ///
     /// #Arguments
     ///
-    /// moving_piece - A GamePiece type reference for the Piece making the move.
-    /// from - A Coordinate type reference denoting starting location.
-    /// to - A Coordinate type reference denoting final location.
+    /// coord - A Coordinate type object denoting location of piece to be checked.
     ///
     /// #Return
     ///
-    /// Returns a bool value telling if the move is valid.
-    fn valid_move(&self, moving_piece: &GamePiece, from: &Coordinate, to: &Coordinate) -> bool {
-        if !to.on_board() || !from.on_board() {
-            false
+    /// Returns a bool value denoting if the piece is crowned or not.
+    pub fn is_crowned(&self, coord: Coordinate) -> bool {
+        let Coordinate(coord_x, coord_y) = coord;
+        match self.board[coord_x][coord_y] {
+            Some(piece) => piece.crowned,
+            None => false,
+        }
+    }
+
+    /// move_count method tells the number of moves made.
+    ///
+    /// #Return
+    ///
+    /// Returns an u32 value denoting the count of moves.
+    pub fn move_count(&self) -> u32 {
+        self.move_count
+    }
+
+    /// set_move_count method restores the number of moves made, for resuming a saved game.
+    ///
+    /// #Arguments
+    ///
+    /// count - an u32 parameter for the number of moves already made.
+    pub fn set_move_count(&mut self, count: u32) {
+        self.move_count = count;
+    }
+
+    /// full_move_number method tells the number of the move pair being played, starting at 1.
+    ///
+    /// #Return
+    ///
+    /// Returns an u32 value which goes up once both players have moved.
+    pub fn full_move_number(&self) -> u32 {
+        self.move_count / 2 + 1
+    }
+
+    /// outcome method tells if the game has ended and how.
+    ///
+    /// The player to move loses when they have no legal move, whether or not they still have
+    /// pieces on the board. The game is drawn with insufficient material or once the rules'
+    /// draw ply limit is reached without progress.
+    ///
+    /// #Return
+    ///
+    /// Returns the Outcome wrapped in Option, None while the game is still in progress.
+    pub fn outcome(&self) -> Option<Outcome> {
+        match self.status {
+            GameStatus::Resigned(color) => return Some(Outcome::Win(color.opponent())),
+            GameStatus::Draw => return Some(Outcome::Draw),
+            _ => {}
+        }
+        if !self.has_legal_move() {
+            Some(Outcome::Win(self.current_turn.opponent()))
+        } else if self.is_drawn() {
+            Some(Outcome::Draw)
         } else {
-            let Coordinate(to_x, to_y) = *to;
-            if let Some(_piece) = self.board[to_x][to_y] {
-                false
-            } else {
-                let Coordinate(_from_x, from_y) = *from;
-                let mut valid = false;
-                if to_y > from_y && moving_piece.color == PieceColor::White {
-                    // white moves down
-                    valid = true;
-                }
-                if to_y < from_y && moving_piece.color == PieceColor::Black {
-                    // black moves up
-                    valid = true;
-                }
-                if to_y > from_y && moving_piece.color == PieceColor::Black && moving_piece.crowned
-                {
-                    // crowned black move down
-                    valid = true;
-                }
-                if to_y < from_y && moving_piece.color == PieceColor::White && moving_piece.crowned
-                {
-                    // crowned white move up
-                    valid = true;
-                }
-                valid
-            }
+            None
         }
     }
-}
 
-#[cfg(test)]
-mod test {
-    use super::super::board::{Coordinate, GamePiece, Move, PieceColor};
-    use super::GameEngine;
+    /// is_game_over method checks if the game has ended for any reason.
+    ///
+    /// #Return
+    ///
+    /// Returns a bool value, true once status is no longer GameStatus::InProgress.
+    pub fn is_game_over(&self) -> bool {
+        self.status() != GameStatus::InProgress
+    }
+
+    /// status method tells whether the game is still being played and how it ended.
+    ///
+    /// #Return
+    ///
+    /// Returns the GameStatus, covering both outcomes on the board and resignation or an agreed draw.
+    pub fn status(&self) -> GameStatus {
+        if self.status != GameStatus::InProgress {
+            return self.status;
+        }
+        match self.outcome() {
+            Some(Outcome::Win(color)) => GameStatus::Won(color),
+            Some(Outcome::Draw) => GameStatus::Draw,
+            None => GameStatus::InProgress,
+        }
+    }
+
+    /// report method gathers the state of the game in one call.
+    ///
+    /// #Return
+    ///
+    /// Returns the instance of type GameReport.
+    pub fn report(&self) -> GameReport {
+        let black_kings = self.king_count(PieceColor::Black);
+        let white_kings = self.king_count(PieceColor::White);
+        let legal_moves = self.legal_moves();
+        GameReport {
+            current_turn: self.current_turn,
+            move_count: self.move_count,
+            black_men: self.piece_count(PieceColor::Black) - black_kings,
+            black_kings,
+            white_men: self.piece_count(PieceColor::White) - white_kings,
+            white_kings,
+            capture_forced: !legal_moves.is_empty() && legal_moves.iter().all(|m| self.is_jump(m)),
+            status: self.status(),
+            legal_move_count: legal_moves.len(),
+        }
+    }
+
+    /// resign method ends the game with a win for the opponent of the resigning color.
+    ///
+    /// #Arguments
+    ///
+    /// color - a parameter of type PieceColor denoting the player who resigns.
+    ///
+    /// #Return
+    ///
+    /// Returns the unit type, or MoveError::GameOver if the game has already ended.
+    pub fn resign(&mut self, color: PieceColor) -> Result<(), MoveError> {
+        if self.status() != GameStatus::InProgress {
+            return Err(MoveError::GameOver);
+        }
+        self.status = GameStatus::Resigned(color);
+        Ok(())
+    }
+
+    /// offer_draw method records a draw offer, which lapses once a move is made.
+    ///
+    /// #Arguments
+    ///
+    /// color - a parameter of type PieceColor denoting the player offering the draw.
+    ///
+    /// #Return
+    ///
+    /// Returns the unit type, or MoveError::GameOver if the game has already ended.
+    pub fn offer_draw(&mut self, color: PieceColor) -> Result<(), MoveError> {
+        if self.status() != GameStatus::InProgress {
+            return Err(MoveError::GameOver);
+        }
+        self.draw_offer = Some(color);
+        Ok(())
+    }
+
+    /// accept_draw method ends the game as a draw if the opponent has offered one.
+    ///
+    /// #Arguments
+    ///
+    /// color - a parameter of type PieceColor denoting the player accepting the draw.
+    ///
+    /// #Return
+    ///
+    /// Returns the unit type, or MoveError::NoDrawOffer if the opponent has no offer pending.
+    pub fn accept_draw(&mut self, color: PieceColor) -> Result<(), MoveError> {
+        if self.status() != GameStatus::InProgress {
+            return Err(MoveError::GameOver);
+        }
+        if self.draw_offer != Some(color.opponent()) {
+            return Err(MoveError::NoDrawOffer);
+        }
+        self.status = GameStatus::Draw;
+        self.draw_offer = None;
+        Ok(())
+    }
+
+    /// is_drawn method checks if the game has ended in a draw, whether or not moves remain.
+    ///
+    /// #Return
+    ///
+    /// Returns a bool value denoting insufficient material or the draw ply limit being reached.
+    pub fn is_drawn(&self) -> bool {
+        self.is_insufficient_material() || self.plies_until_draw() == Some(0)
+    }
+
+    /// is_insufficient_material method checks if neither side can force a win.
+    ///
+    /// This is a simplified heuristic: a lone king against a lone king is a draw unless the
+    /// side to move can capture straight away.
+    ///
+    /// #Return
+    ///
+    /// Returns a bool value denoting if the position is a dead draw.
+    pub fn is_insufficient_material(&self) -> bool {
+        self.piece_count(PieceColor::Black) == 1
+            && self.piece_count(PieceColor::White) == 1
+            && self.king_count(PieceColor::Black) == 1
+            && self.king_count(PieceColor::White) == 1
+            && !self.has_capture()
+    }
+
+    /// winner method tells the player who has won the game.
+    ///
+    /// #Return
+    ///
+    /// Returns the PieceColor of the winner wrapped in Option, None for a drawn or unfinished game.
+    pub fn winner(&self) -> Option<PieceColor> {
+        match self.outcome() {
+            Some(Outcome::Win(color)) => Some(color),
+            _ => None,
+        }
+    }
+
+    /// jumping_piece method tells which piece is part way through a multi-jump.
+    ///
+    /// #Return
+    ///
+    /// Returns the Coordinate of the piece which must keep capturing wrapped in Option.
+    pub fn jumping_piece(&self) -> Option<Coordinate> {
+        self.jumping_piece
+    }
+
+    /// last_move method tells the most recent move, from the start to the end of a multi-jump.
+    ///
+    /// #Return
+    ///
+    /// Returns the last Move wrapped in Option, None before any move is made.
+    pub fn last_move(&self) -> Option<Move> {
+        self.last_move
+    }
+
+    /// history method gives every move made since the game started or the position was last
+    /// edited, one entry per hop of a multi-jump.
+    ///
+    /// #Return
+    ///
+    /// Returns the slice of MoveResult in the order the moves were made.
+    pub fn history(&self) -> &[MoveResult] {
+        &self.history
+    }
+
+    /// history_deltas method gives the squares each move in history changed.
+    ///
+    /// #Return
+    ///
+    /// Returns the vector of BoardDelta, one per entry of history, in the order the moves were
+    /// made.
+    pub fn history_deltas(&self) -> Vec<BoardDelta> {
+        let mut boards = self
+            .snapshots
+            .iter()
+            .map(|snapshot| &snapshot.board)
+            .collect::<Vec<_>>();
+        boards.push(&self.board);
+        boards
+            .windows(2)
+            .map(|pair| BoardDelta {
+                changes: board_diff(pair[0], pair[1]),
+            })
+            .collect()
+    }
+
+    /// black_captured method counts the black pieces taken so far.
+    ///
+    /// #Return
+    ///
+    /// Returns an u32 value denoting the number of captured black pieces.
+    pub fn black_captured(&self) -> u32 {
+        self.black_captured
+    }
+
+    /// white_captured method counts the white pieces taken so far.
+    ///
+    /// #Return
+    ///
+    /// Returns an u32 value denoting the number of captured white pieces.
+    pub fn white_captured(&self) -> u32 {
+        self.white_captured
+    }
+
+    /// piece_count method counts the pieces of a color on the board.
+    ///
+    /// #Arguments
+    ///
+    /// color - a parameter of type PieceColor denoting color of pieces to count.
+    ///
+    /// #Return
+    ///
+    /// Returns an usize value denoting the count of pieces.
+    pub fn piece_count(&self, color: PieceColor) -> usize {
+        self.pieces_of(color).count()
+    }
+
+    /// pieces_of method walks over the pieces of one color, column by column.
+    ///
+    /// #Arguments
+    ///
+    /// color - a parameter of type PieceColor denoting the side whose pieces are wanted.
+    ///
+    /// #Return
+    ///
+    /// Returns an iterator over the Coordinate and GamePiece of each piece of color.
+    pub fn pieces_of(
+        &self,
+        color: PieceColor,
+    ) -> impl Iterator<Item = (Coordinate, GamePiece)> + '_ {
+        self.board.iter().enumerate().flat_map(move |(x, col)| {
+            col.iter()
+                .enumerate()
+                .filter_map(move |(y, square)| match square {
+                    Some(piece) if piece.color == color => Some((Coordinate(x, y), *piece)),
+                    _ => None,
+                })
+        })
+    }
+
+    /// board_rows method gives a row-major copy of the board for rendering.
+    ///
+    /// The engine stores the board column-major as board[x][y]. The copy is indexed
+    /// board_rows[y][x] instead, so row 0 is the top of the board where white starts and
+    /// each inner array runs from x = 0 on the left.
+    ///
+    /// #Return
+    ///
+    /// Returns the rows of the board, each holding an Option of GamePiece per square.
+    pub fn board_rows(&self) -> [[Option<GamePiece>; BOARD_SIZE]; BOARD_SIZE] {
+        let mut rows = [[None; BOARD_SIZE]; BOARD_SIZE];
+        for (x, col) in self.board.iter().enumerate() {
+            for (y, square) in col.iter().enumerate() {
+                rows[y][x] = *square;
+            }
+        }
+        rows
+    }
+
+    /// render_ascii method draws the board as text, one line per row starting from the top.
+    ///
+    /// Black pieces are 'b', white pieces 'w', kings are upper case and empty squares are '.'.
+    ///
+    /// #Return
+    ///
+    /// Returns the String holding eight lines of eight characters.
+    pub fn render_ascii(&self) -> String {
+        self.render(&[])
+    }
+
+    /// render_with_hints method draws the board as render_ascii does, marking with '*' every
+    /// square the current player can move a piece to.
+    ///
+    /// #Return
+    ///
+    /// Returns the String holding eight lines of eight characters.
+    pub fn render_with_hints(&self) -> String {
+        let destinations = self
+            .legal_moves()
+            .into_iter()
+            .map(|m| m.to)
+            .collect::<Vec<Coordinate>>();
+        self.render(&destinations)
+    }
+
+    /// render method draws the board as text with some squares marked.
+    ///
+    /// #Arguments
+    ///
+    /// marked - a slice of Coordinate to be drawn as '*'.
+    ///
+    /// #Return
+    ///
+    /// Returns the String holding eight lines of eight characters.
+    fn render(&self, marked: &[Coordinate]) -> String {
+        let mut text = String::new();
+        for (y, row) in self.board_rows().iter().enumerate() {
+            for (x, square) in row.iter().enumerate() {
+                text.push(match square {
+                    Some(piece) => match (piece.color, piece.crowned) {
+                        (PieceColor::Black, false) => 'b',
+                        (PieceColor::Black, true) => 'B',
+                        (PieceColor::White, false) => 'w',
+                        (PieceColor::White, true) => 'W',
+                    },
+                    None if marked.contains(&Coordinate(x, y)) => '*',
+                    None => '.',
+                });
+            }
+            text.push('\n');
+        }
+        text
+    }
+
+    /// find_pieces method locates every piece matching a predicate.
+    ///
+    /// #Arguments
+    ///
+    /// pred - a closure deciding whether a GamePiece should be included.
+    ///
+    /// #Return
+    ///
+    /// Returns the vector of Coordinate holding matching pieces, in row-major order.
+    pub fn find_pieces(&self, pred: impl Fn(&GamePiece) -> bool) -> Vec<Coordinate> {
+        self.playable_squares()
+            .filter(|&Coordinate(x, y)| matches!(self.board[x][y], Some(ref piece) if pred(piece)))
+            .collect()
+    }
+
+    /// playable_squares method gives the squares pieces can stand on, in row-major order.
+    ///
+    /// #Return
+    ///
+    /// Returns an iterator over the 32 dark squares, or over all 64 squares under
+    /// orthogonal_moves.
+    fn playable_squares(&self) -> impl Iterator<Item = Coordinate> {
+        let all_squares = self.rules.orthogonal_moves;
+        (0..8)
+            .flat_map(|y_coord| (0..8).map(move |x_coord| Coordinate(x_coord, y_coord)))
+            .filter(move |&Coordinate(x_coord, y_coord)| {
+                all_squares || (x_coord + y_coord) % 2 == 1
+            })
+    }
+
+    /// king_count method counts the crowned pieces of a color on the board.
+    ///
+    /// #Arguments
+    ///
+    /// color - a parameter of type PieceColor denoting color of kings to count.
+    ///
+    /// #Return
+    ///
+    /// Returns an usize value denoting the count of kings.
+    pub fn king_count(&self, color: PieceColor) -> usize {
+        self.board
+            .iter()
+            .flat_map(|col| col.iter())
+            .filter(|square| matches!(square, Some(piece) if piece.color == color && piece.crowned))
+            .count()
+    }
+
+    /// crown_all method crowns every piece on the board, for setting up king positions in tests.
+    #[cfg(any(test, feature = "test-utils"))]
+    pub fn crown_all(&mut self) {
+        for col in self.board.iter_mut() {
+            for square in col.iter_mut() {
+                if let Some(piece) = *square {
+                    *square = Some(GamePiece::crowned(piece));
+                }
+            }
+        }
+        self.forget_history();
+    }
+
+    /// material_balance method weighs the pieces on the board, counting kings double.
+    ///
+    /// #Return
+    ///
+    /// Returns an i32 value, positive when black is ahead and negative when white is ahead.
+    pub fn material_balance(&self) -> i32 {
+        self.board
+            .iter()
+            .flat_map(|col| col.iter())
+            .filter_map(|square| *square)
+            .map(|piece| {
+                let value = if piece.crowned { 2 } else { 1 };
+                match piece.color {
+                    PieceColor::Black => value,
+                    PieceColor::White => -value,
+                }
+            })
+            .sum()
+    }
+
+    /// edge_piece_count method counts a color's pieces on the left and right edges, where they
+    /// can't be jumped.
+    ///
+    /// #Arguments
+    ///
+    /// color - a parameter of type PieceColor denoting the side to count.
+    ///
+    /// #Return
+    ///
+    /// Returns an usize value denoting the pieces on the first and last columns.
+    pub fn edge_piece_count(&self, color: PieceColor) -> usize {
+        [0, BOARD_SIZE - 1]
+            .iter()
+            .flat_map(|&x| self.board[x].iter())
+            .filter(|square| matches!(square, Some(piece) if piece.color == color))
+            .count()
+    }
+
+    /// back_row_intact method checks if a color still holds every playable square of its home
+    /// row, which keeps the opponent from crowning.
+    ///
+    /// #Arguments
+    ///
+    /// color - a parameter of type PieceColor denoting the side to check.
+    ///
+    /// #Return
+    ///
+    /// Returns a bool value, true when each playable square of the home row holds a piece of
+    /// color.
+    pub fn back_row_intact(&self, color: PieceColor) -> bool {
+        let home_row = color.opponent().crown_row(BOARD_SIZE, ORIENTATION);
+        self.playable_squares()
+            .filter(|&Coordinate(_, y)| y == home_row)
+            .all(|coord| matches!(self.piece_at(coord), Some(piece) if piece.color == color))
+    }
+
+    /// advancement_score method sums how far a color's men have moved toward their crowning row.
+    ///
+    /// #Arguments
+    ///
+    /// color - a parameter of type PieceColor denoting the side to score.
+    ///
+    /// #Return
+    ///
+    /// Returns an u32 value adding the rows each man has advanced from the home row, and
+    /// KING_ADVANCEMENT for each king.
+    pub fn advancement_score(&self, color: PieceColor) -> u32 {
+        let home_row = color.opponent().crown_row(BOARD_SIZE, ORIENTATION);
+        self.find_pieces(|piece| piece.color == color)
+            .into_iter()
+            .map(|coord| {
+                if self.is_crowned(coord) {
+                    KING_ADVANCEMENT
+                } else {
+                    coord.1.abs_diff(home_row) as u32
+                }
+            })
+            .sum()
+    }
+
+    /// king_safety method scores how sheltered a color's kings are from capture.
+    ///
+    /// A king on an edge can't be jumped across it, so each king scores how close it stands to
+    /// the nearest side and the nearest end of the board, up to 3 for each, and a king in a
+    /// corner scores the most. KING_THREAT_PENALTY is taken off for each king the opponent could
+    /// capture next turn.
+    ///
+    /// #Arguments
+    ///
+    /// color - a parameter of type PieceColor denoting the side whose kings are scored.
+    ///
+    /// #Return
+    ///
+    /// Returns an i32 value, higher for safer kings and 0 when color has no kings.
+    pub fn king_safety(&self, color: PieceColor) -> i32 {
+        let threatened = self.attacked_squares(color.opponent());
+        let shelter = |z: usize| (BOARD_SIZE / 2 - 1 - z.min(BOARD_SIZE - 1 - z)) as i32;
+        self.pieces_of(color)
+            .filter(|&(_, piece)| piece.crowned)
+            .map(|(coord, _)| {
+                let Coordinate(x, y) = coord;
+                let penalty = if threatened.contains(&coord) {
+                    KING_THREAT_PENALTY
+                } else {
+                    0
+                };
+                shelter(x) + shelter(y) - penalty
+            })
+            .sum()
+    }
+
+    /// mobility method counts the legal moves a color would have on its turn.
+    ///
+    /// #Arguments
+    ///
+    /// color - a parameter of type PieceColor denoting the side to be counted.
+    ///
+    /// #Return
+    ///
+    /// Returns an usize value denoting the number of legal moves.
+    pub fn mobility(&self, color: PieceColor) -> usize {
+        if color == self.current_turn {
+            self.legal_moves().len()
+        } else {
+            let mut other = self.search_copy();
+            other.current_turn = color;
+            other.jumping_piece = None;
+            other.legal_moves().len()
+        }
+    }
+
+    /// plies_since_progress method tells the number of plies since the last capture or crowning.
+    ///
+    /// #Return
+    ///
+    /// Returns an u32 value denoting the count of plies without progress.
+    pub fn plies_since_progress(&self) -> u32 {
+        self.plies_since_progress
+    }
+
+    /// plies_until_draw method tells how many more plies without progress draw the game.
+    ///
+    /// #Return
+    ///
+    /// Returns an u32 value wrapped in Option, None when the rules disable draw by inactivity.
+    pub fn plies_until_draw(&self) -> Option<u32> {
+        self.rules
+            .draw_ply_limit
+            .map(|limit| limit.saturating_sub(self.plies_since_progress))
+    }
+
+    /// diff method lists the squares which differ from another position.
+    ///
+    /// #Arguments
+    ///
+    /// other - A GameEngine type reference for the position to be compared with.
+    ///
+    /// #Return
+    ///
+    /// Returns the vector of changed Coordinate with the piece it holds in other.
+    pub fn diff(&self, other: &GameEngine) -> Vec<(Coordinate, Option<GamePiece>)> {
+        board_diff(&self.board, &other.board)
+    }
+
+    /// apply_delta method writes the squares of a BoardDelta onto the board.
+    ///
+    /// Only the board changes, the side to move and counters are left as they are. The moves
+    /// recorded for undo are dropped.
+    ///
+    /// #Arguments
+    ///
+    /// delta - a reference of type BoardDelta holding the squares to be written.
+    pub fn apply_delta(&mut self, delta: &BoardDelta) {
+        for &(Coordinate(x, y), piece) in &delta.changes {
+            self.board[x][y] = piece;
+        }
+        self.forget_history();
+    }
+
+    /// mirror method gives the equivalent position with the colors swapped.
+    ///
+    /// Every piece changes color and the board is turned half a turn, flipping it vertically
+    /// and horizontally so that pieces stay on dark squares, and the other side is to move.
+    ///
+    /// #Return
+    ///
+    /// Returns the mirrored instance of type GameEngine.
+    pub fn mirror(&self) -> GameEngine {
+        let mut mirrored = self.clone();
+        for col in 0..8 {
+            for row in 0..8 {
+                mirrored.board[7 - col][7 - row] = self.board[col][row].map(|piece| GamePiece {
+                    color: piece.color.opponent(),
+                    crowned: piece.crowned,
+                });
+            }
+        }
+        mirrored.current_turn = self.current_turn.opponent();
+        mirrored.status = match self.status {
+            GameStatus::Won(color) => GameStatus::Won(color.opponent()),
+            GameStatus::Resigned(color) => GameStatus::Resigned(color.opponent()),
+            status => status,
+        };
+        mirrored.draw_offer = self.draw_offer.map(PieceColor::opponent);
+        mirrored.black_captured = self.white_captured;
+        mirrored.white_captured = self.black_captured;
+        mirrored.jumping_piece = self
+            .jumping_piece
+            .map(|Coordinate(x, y)| Coordinate(7 - x, 7 - y));
+        mirrored.pending_captures = self
+            .pending_captures
+            .iter()
+            .map(|&Coordinate(x, y)| Coordinate(7 - x, 7 - y))
+            .collect();
+        // the recorded moves are of the unmirrored board
+        mirrored.forget_history();
+        mirrored
+    }
+
+    /// swap_side_to_move method hands the turn to the other player without counting a move.
+    ///
+    /// Any multi-jump in progress is ended and its deferred captures are taken off the board.
+    pub fn swap_side_to_move(&mut self) {
+        self.jumping_piece = None;
+        for Coordinate(x, y) in self.pending_captures.drain(..) {
+            self.board[x][y] = None;
+        }
+        self.current_turn = self.current_turn.opponent();
+        self.forget_history();
+    }
+
+    /// swap_all_colors method recolors every piece on the board, leaving each where it stands.
+    pub fn swap_all_colors(&mut self) {
+        for piece in self
+            .board
+            .iter_mut()
+            .flat_map(|col| col.iter_mut())
+            .flatten()
+        {
+            piece.color = piece.color.opponent();
+        }
+        self.forget_history();
+    }
+
+    /// zobrist_hash method gives a hash of the position for transposition lookups.
+    ///
+    /// #Return
+    ///
+    /// Returns an u64 value combining the pieces on the board, the side to move, the piece part
+    /// way through a multi-jump and the pieces it has jumped under deferred removal.
+    pub fn zobrist_hash(&self) -> u64 {
+        let mut hash = 0;
+        for col in 0..8 {
+            for row in 0..8 {
+                if let Some(piece) = self.board[col][row] {
+                    let kind = match (piece.color, piece.crowned) {
+                        (PieceColor::Black, false) => 0,
+                        (PieceColor::Black, true) => 1,
+                        (PieceColor::White, false) => 2,
+                        (PieceColor::White, true) => 3,
+                    };
+                    hash ^= zobrist_key(((col * 8 + row) * 4 + kind) as u64);
+                }
+            }
+        }
+        if self.current_turn == PieceColor::White {
+            hash ^= zobrist_key(256);
+        }
+        if let Some(Coordinate(x, y)) = self.jumping_piece {
+            hash ^= zobrist_key((257 + x * 8 + y) as u64);
+        }
+        for &Coordinate(x, y) in &self.pending_captures {
+            hash ^= zobrist_key((321 + x * 8 + y) as u64);
+        }
+        hash
+    }
+
+    /// state_json method describes the game as a JSON object for user interfaces.
+    ///
+    /// The object has the keys "board" (rows of piece codes indexed board[y][x], using the
+    /// FFI encoding with -1 for empty squares), "current_turn" ("black" or "white"),
+    /// "move_count", "winner" ("black", "white", "draw" or null) and "legal_moves"
+    /// (an array of [from_x, from_y, to_x, to_y]).
+    ///
+    /// #Return
+    ///
+    /// Returns the JSON encoded String.
+    pub fn state_json(&self) -> String {
+        let color_name = |color: PieceColor| match color {
+            PieceColor::Black => "black",
+            PieceColor::White => "white",
+        };
+
+        let rows = (0..8)
+            .map(|y| {
+                let cells = (0..8)
+                    .map(|x| self.board[x][y].map_or(-1, Into::into).to_string())
+                    .collect::<Vec<String>>();
+                format!("[{}]", cells.join(","))
+            })
+            .collect::<Vec<String>>();
+        let winner = match self.outcome() {
+            Some(Outcome::Win(color)) => format!("\"{}\"", color_name(color)),
+            Some(Outcome::Draw) => "\"draw\"".to_string(),
+            None => "null".to_string(),
+        };
+        let moves = self
+            .legal_moves()
+            .iter()
+            .map(|m| {
+                let Coordinate(from_x, from_y) = m.from;
+                let Coordinate(to_x, to_y) = m.to;
+                format!("[{},{},{},{}]", from_x, from_y, to_x, to_y)
+            })
+            .collect::<Vec<String>>();
+
+        format!(
+            "{{\"board\":[{}],\"current_turn\":\"{}\",\"move_count\":{},\"winner\":{},\"legal_moves\":[{}]}}",
+            rows.join(","),
+            color_name(self.current_turn),
+            self.move_count,
+            winner,
+            moves.join(",")
+        )
+    }
+
+    /// to_bytes method encodes the position into a compact byte array.
+    ///
+    /// The 32 dark squares take 2 bits each (0 empty, 1 black, 2 white) in the first 8 bytes,
+    /// followed by a 4 byte little endian bitmask of crowned squares and a side-to-move byte.
+    /// Under orthogonal_moves all 64 squares are encoded the same way, in 16 bytes followed by
+    /// an 8 byte bitmask.
+    ///
+    /// #Return
+    ///
+    /// Returns a vector of ENCODED_LEN bytes, or ENCODED_LEN_ALL_SQUARES under orthogonal_moves.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let squares = self.playable_squares().collect::<Vec<Coordinate>>();
+        let kings_start = squares.len() / 4;
+        let turn_index = kings_start + squares.len() / 8;
+        let mut bytes = vec![0u8; turn_index + 1];
+        let mut kings: u64 = 0;
+        for (index, Coordinate(x, y)) in squares.into_iter().enumerate() {
+            if let Some(piece) = self.board[x][y] {
+                let code = match piece.color {
+                    PieceColor::Black => 1,
+                    PieceColor::White => 2,
+                };
+                bytes[index / 4] |= code << ((index % 4) * 2);
+                if piece.crowned {
+                    kings |= 1 << index;
+                }
+            }
+        }
+        bytes[kings_start..turn_index]
+            .copy_from_slice(&kings.to_le_bytes()[..turn_index - kings_start]);
+        bytes[turn_index] = match self.current_turn {
+            PieceColor::Black => 0,
+            PieceColor::White => 1,
+        };
+        bytes
+    }
+
+    /// board_checksum method gives a checksum of the position that is stable across machines.
+    ///
+    /// It is the 32-bit FNV-1a hash of the fixed to_bytes encoding, so networked clients can
+    /// compare it to detect boards that have diverged.
+    ///
+    /// #Return
+    ///
+    /// Returns an u32 value over the pieces on the board and the side to move.
+    pub fn board_checksum(&self) -> u32 {
+        self.to_bytes()
+            .iter()
+            .fold(0x811c_9dc5, |hash: u32, &byte| {
+                (hash ^ u32::from(byte)).wrapping_mul(0x0100_0193)
+            })
+    }
+
+    /// from_bytes method decodes a position produced by to_bytes.
+    ///
+    /// A buffer of ENCODED_LEN_ALL_SQUARES bytes decodes to a game with the orthogonal_moves
+    /// rule, any other to one with the default rules.
+    ///
+    /// #Arguments
+    ///
+    /// bytes - a slice holding the encoded position.
+    ///
+    /// #Return
+    ///
+    /// Returns the decoded GameEngine, or a DecodeError if the buffer is malformed.
+    pub fn from_bytes(bytes: &[u8]) -> Result<GameEngine, DecodeError> {
+        if bytes.len() < ENCODED_LEN {
+            return Err(DecodeError::Truncated);
+        }
+        let rules = match bytes.len() {
+            ENCODED_LEN => Rules::default(),
+            ENCODED_LEN_ALL_SQUARES => Rules {
+                orthogonal_moves: true,
+                ..Rules::default()
+            },
+            _ => return Err(DecodeError::TrailingBytes),
+        };
+
+        let turn_index = bytes.len() - 1;
+        let current_turn = match bytes[turn_index] {
+            0 => PieceColor::Black,
+            1 => PieceColor::White,
+            _ => return Err(DecodeError::InvalidTurn),
+        };
+        let mut engine = GameEngine {
+            board: [[None; BOARD_SIZE]; BOARD_SIZE],
+            current_turn,
+            move_count: 0,
+            plies_since_progress: 0,
+            rules,
+            jumping_piece: None,
+            pending_captures: Vec::new(),
+            last_move: None,
+            history: Vec::new(),
+            snapshots: Vec::new(),
+            recording: true,
+            black_captured: 0,
+            white_captured: 0,
+            status: GameStatus::InProgress,
+            draw_offer: None,
+            // a position played along ranks and files restarts from the Turkish setup
+            layout: if rules.orthogonal_moves {
+                Layout::Turkish
+            } else {
+                Layout::Standard
+            },
+            first_turn: PieceColor::Black,
+        };
+
+        let squares = engine.playable_squares().collect::<Vec<Coordinate>>();
+        let kings_start = squares.len() / 4;
+        let mut king_bytes = [0u8; 8];
+        king_bytes[..turn_index - kings_start].copy_from_slice(&bytes[kings_start..turn_index]);
+        let kings = u64::from_le_bytes(king_bytes);
+        for (index, Coordinate(x, y)) in squares.into_iter().enumerate() {
+            let crowned = kings & (1 << index) != 0;
+            let color = match (bytes[index / 4] >> ((index % 4) * 2)) & 0b11 {
+                0 if crowned => return Err(DecodeError::InvalidSquare),
+                0 => continue,
+                1 => PieceColor::Black,
+                2 => PieceColor::White,
+                _ => return Err(DecodeError::InvalidSquare),
+            };
+            engine.board[x][y] = Some(GamePiece { color, crowned });
+        }
+        Ok(engine)
+    }
+
+    /// check_invariants method verifies the internal consistency of the engine, for debugging.
+    ///
+    /// #Return
+    ///
+    /// Returns the unit type, or a String describing the first invariant found broken.
+    pub fn check_invariants(&self) -> Result<(), String> {
+        for (x, col) in self.board.iter().enumerate() {
+            for (y, square) in col.iter().enumerate() {
+                if square.is_some() && (x + y) % 2 == 0 && !self.rules.orthogonal_moves {
+                    return Err(format!("piece on light square ({}, {})", x, y));
+                }
+            }
+        }
+        let max_pieces = if self.rules.orthogonal_moves { 16 } else { 12 };
+        for &color in &[PieceColor::Black, PieceColor::White] {
+            let count = self.piece_count(color);
+            if count > max_pieces {
+                return Err(format!(
+                    "{:?} has {} pieces, more than {}",
+                    color, count, max_pieces
+                ));
+            }
+        }
+        if let Some(coord) = self.jumping_piece {
+            if !coord.on_board() {
+                return Err(format!("jumping piece {:?} is off the board", coord));
+            }
+            match self.piece_at(coord) {
+                Some(piece) if piece.color == self.current_turn => {}
+                _ => {
+                    return Err(format!(
+                        "jumping piece {:?} does not belong to {:?}",
+                        coord, self.current_turn
+                    ))
+                }
+            }
+        }
+        if let Some(coord) = self.pending_captures.iter().find(|coord| !coord.on_board()) {
+            return Err(format!("pending capture {:?} is off the board", coord));
+        }
+        Ok(())
+    }
+
+    /// legal_moves method gives all the legal moves for all locations on the board.
+    ///
+    /// #Return
+    ///
+    /// Returns vector containing the legal moves, each Move appearing once.
+    pub fn legal_moves(&self) -> Vec<Move> {
+        let mut moves: Vec<Move> = Vec::new();
+        self.legal_moves_into(&mut moves);
+        moves
+    }
+
+    /// legal_moves_into method fills a buffer with the legal moves, reusing its allocation.
+    ///
+    /// #Arguments
+    ///
+    /// moves - a mutable reference of type Vec of Move, cleared before it is filled.
+    pub fn legal_moves_into(&self, moves: &mut Vec<Move>) {
+        moves.clear();
+        if let Some(loc) = self.jumping_piece {
+            moves.extend(self.jumps_from(loc));
+        } else {
+            for (loc, _) in self.pieces_of(self.current_turn) {
+                for vmove in self.valid_moves_from(loc) {
+                    // the geometry helpers may reach a target twice near the edges
+                    if !moves.contains(&vmove) {
+                        moves.push(vmove);
+                    }
+                }
+            }
+        }
+
+        if self.rules.mandatory_capture && moves.iter().any(|m| self.is_jump(m)) {
+            moves.retain(|m| self.is_jump(m));
+        }
+        if self.rules.longest_capture {
+            let lengths = moves
+                .iter()
+                .map(|m| self.capture_length(m))
+                .collect::<Vec<usize>>();
+            let longest = lengths.iter().cloned().max().unwrap_or(0);
+            let mut lengths = lengths.into_iter();
+            moves.retain(|_| {
+                let length = lengths.next().unwrap();
+                length == 0 || length == longest
+            });
+        }
+    }
+
+    /// max_capture_length method tells how many pieces the longest capture available this turn takes.
+    ///
+    /// #Return
+    ///
+    /// Returns an usize value denoting the pieces captured, 0 if no capture is available.
+    pub fn max_capture_length(&self) -> usize {
+        self.legal_moves()
+            .iter()
+            .map(|m| self.capture_length(m))
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// with_move_applied method gives a copy of the engine with a move made, leaving self untouched.
+    ///
+    /// #Arguments
+    ///
+    /// m - a reference of type Move to be made on the copy.
+    ///
+    /// #Return
+    ///
+    /// Returns the new instance of type GameEngine, or the MoveError if the move can't be made.
+    pub fn with_move_applied(&self, m: &Move) -> Result<GameEngine, MoveError> {
+        let mut child = self.clone();
+        child.move_piece(m)?;
+        Ok(child)
+    }
+
+    /// successors method expands the position by every legal move, for building search trees.
+    ///
+    /// #Return
+    ///
+    /// Returns the vector of each legal Move paired with the GameEngine after it is made. The
+    /// children don't carry the game record, so they have nothing to undo.
+    pub fn successors(&self) -> Vec<(Move, GameEngine)> {
+        self.legal_moves()
+            .into_iter()
+            .filter_map(|m| {
+                let mut child = self.search_copy();
+                child.move_piece(&m).ok().map(|_| (m, child))
+            })
+            .collect()
+    }
+
+    /// has_legal_move method checks if the current player can move, stopping at the first move found.
+    ///
+    /// #Return
+    ///
+    /// Returns a bool value, the same as !legal_moves().is_empty().
+    pub fn has_legal_move(&self) -> bool {
+        if let Some(loc) = self.jumping_piece {
+            return self.has_jump_from(loc);
+        }
+        (0..8)
+            .flat_map(|x_coord| (0..8).map(move |y_coord| Coordinate(x_coord, y_coord)))
+            .any(|loc| {
+                matches!(self.piece_at(loc), Some(piece) if piece.color == self.current_turn)
+                    && !self.valid_moves_from(loc).is_empty()
+            })
+    }
+
+    /// has_capture method checks if the current player has any jump available.
+    ///
+    /// #Return
+    ///
+    /// Returns a bool value, stopping at the first jump found.
+    pub fn has_capture(&self) -> bool {
+        if self.jumping_piece.is_some() {
+            return true;
+        }
+        (0..8)
+            .flat_map(|col| (0..8).map(move |row| Coordinate(col, row)))
+            .any(|loc| {
+                matches!(self.piece_at(loc), Some(piece) if piece.color == self.current_turn)
+                    && self.has_jump_from(loc)
+            })
+    }
+
+    /// attacked_squares method gives the pieces a color could capture if it were its turn.
+    ///
+    /// Only the first hop of each capture is considered.
+    ///
+    /// #Arguments
+    ///
+    /// color - a parameter of type PieceColor denoting the attacking side.
+    ///
+    /// #Return
+    ///
+    /// Returns the vector of Coordinate of the pieces that can be jumped.
+    pub fn attacked_squares(&self, color: PieceColor) -> Vec<Coordinate> {
+        let mut attacked: Vec<Coordinate> = Vec::new();
+        for col in 0..8 {
+            for row in 0..8 {
+                if !matches!(self.board[col][row], Some(piece) if piece.color == color) {
+                    continue;
+                }
+                for jump in self.jumps_from(Coordinate(col, row)) {
+                    let Coordinate(from_x, from_y) = jump.from;
+                    let Coordinate(to_x, to_y) = jump.to;
+                    if let Some(coord) = self.midpiece_coordinate(from_x, from_y, to_x, to_y) {
+                        if !attacked.contains(&coord) {
+                            attacked.push(coord);
+                        }
+                    }
+                }
+            }
+        }
+        attacked
+    }
+
+    /// pieces_in_danger method gives the current player's pieces the opponent could capture next turn.
+    ///
+    /// #Return
+    ///
+    /// Returns the vector of Coordinate of the current player's hanging pieces.
+    pub fn pieces_in_danger(&self) -> Vec<Coordinate> {
+        self.attacked_squares(self.current_turn.opponent())
+    }
+
+    /// en_prise_value method totals the material of a color that the opponent could capture next turn.
+    ///
+    /// #Arguments
+    ///
+    /// color - a parameter of type PieceColor denoting the side whose pieces are at risk.
+    ///
+    /// #Return
+    ///
+    /// Returns an i32 value where a man counts 1 and a king counts 2.
+    pub fn en_prise_value(&self, color: PieceColor) -> i32 {
+        self.attacked_squares(color.opponent())
+            .into_iter()
+            .filter_map(|Coordinate(x, y)| self.board[x][y])
+            .filter(|piece| piece.color == color)
+            .map(|piece| if piece.crowned { 2 } else { 1 })
+            .sum()
+    }
+
+    /// threats_after method gives the current player's pieces that a move would leave capturable.
+    ///
+    /// #Arguments
+    ///
+    /// move_desired - a reference of type Move to be looked ahead.
+    ///
+    /// #Return
+    ///
+    /// Returns the vector of Coordinate of threatened pieces after the move, empty if it is illegal.
+    pub fn threats_after(&self, move_desired: &Move) -> Vec<Coordinate> {
+        let mut child = self.search_copy();
+        if child.move_piece(move_desired).is_err() {
+            return Vec::new();
+        }
+        child.attacked_squares(self.current_turn.opponent())
+    }
+
+    /// describe_move method writes a move in algebraic notation for move lists.
+    ///
+    /// Files a to h run along x and ranks 1 to 8 run up from black's side, so a black man's
+    /// opening move reads like "a3-b4" and a capture like "d4xb6 (captures c5)". A square off
+    /// the board is written "??".
+    ///
+    /// #Arguments
+    ///
+    /// move_desired - a reference of type Move to be described.
+    ///
+    /// #Return
+    ///
+    /// Returns the String describing the move.
+    pub fn describe_move(&self, move_desired: &Move) -> String {
+        let from = square_name(move_desired.from);
+        let to = square_name(move_desired.to);
+        if !move_desired.from.on_board() || !move_desired.to.on_board() {
+            return format!("{}-{}", from, to);
+        }
+        let Coordinate(from_x, from_y) = move_desired.from;
+        let Coordinate(to_x, to_y) = move_desired.to;
+        match self.midpiece_coordinate(from_x, from_y, to_x, to_y) {
+            Some(captured) if self.is_jump(move_desired) => {
+                format!("{}x{} (captures {})", from, to, square_name(captured))
+            }
+            _ => format!("{}-{}", from, to),
+        }
+    }
+
+    /// is_move_safe method checks if a move leaves the moved piece open to immediate capture.
+    ///
+    /// This is a one-ply tactical check. A hop that continues a multi-jump is judged safe,
+    /// since the piece does not come to rest on its landing square.
+    ///
+    /// #Arguments
+    ///
+    /// move_desired - a reference of type Move to be checked.
+    ///
+    /// #Return
+    ///
+    /// Returns a bool value, false when the move is illegal or the opponent could capture the
+    /// moved piece on their reply.
+    pub fn is_move_safe(&self, move_desired: &Move) -> bool {
+        let mut child = self.search_copy();
+        if child.move_piece(move_desired).is_err() {
+            return false;
+        }
+        child.current_turn == self.current_turn
+            || !child
+                .attacked_squares(child.current_turn)
+                .contains(&move_desired.to)
+    }
+
+    /// capture_moves method gives the legal moves which capture a piece.
+    ///
+    /// #Return
+    ///
+    /// Returns the vector of legal jumps.
+    pub fn capture_moves(&self) -> Vec<Move> {
+        self.legal_moves()
+            .into_iter()
+            .filter(|m| self.is_jump(m))
+            .collect()
+    }
+
+    /// quiet_moves method gives the legal moves which don't capture, empty when a capture is forced.
+    ///
+    /// #Return
+    ///
+    /// Returns the vector of legal simple moves.
+    pub fn quiet_moves(&self) -> Vec<Move> {
+        self.legal_moves()
+            .into_iter()
+            .filter(|m| !self.is_jump(m))
+            .collect()
+    }
+
+    /// legal_moves_sorted method gives the legal moves in a documented, stable order.
+    ///
+    /// Moves are ordered by the square number of their origin, then by the square number of
+    /// their destination, independent of how the board is scanned internally.
+    ///
+    /// #Return
+    ///
+    /// Returns vector containing the sorted legal moves.
+    pub fn legal_moves_sorted(&self) -> Vec<Move> {
+        let mut moves = self.legal_moves();
+        moves.sort_by_key(|m| {
+            (
+                m.from.to_square_number(BOARD_SIZE),
+                m.to.to_square_number(BOARD_SIZE),
+            )
+        });
+        moves
+    }
+
+    /// moves_by_origin method groups the legal moves by the piece that makes them.
+    ///
+    /// #Return
+    ///
+    /// Returns the HashMap from each movable piece's Coordinate to its destination squares.
+    #[cfg(feature = "std")]
+    pub fn moves_by_origin(&self) -> HashMap<Coordinate, Vec<Coordinate>> {
+        let mut grouped: HashMap<Coordinate, Vec<Coordinate>> = HashMap::new();
+        for m in self.legal_moves() {
+            grouped.entry(m.from).or_default().push(m.to);
+        }
+        grouped
+    }
+
+    /// mobility_map method counts the moves of each of the current player's pieces for heatmaps.
+    ///
+    /// Capture rules restricting the turn as a whole, such as mandatory capture, are not applied.
+    ///
+    /// #Return
+    ///
+    /// Returns the HashMap from each of the current player's pieces to its number of valid moves.
+    #[cfg(feature = "std")]
+    pub fn mobility_map(&self) -> HashMap<Coordinate, usize> {
+        self.find_pieces(|piece| piece.color == self.current_turn)
+            .into_iter()
+            .map(|loc| {
+                let mut targets: Vec<Coordinate> = Vec::new();
+                for m in self.valid_moves_from(loc) {
+                    if !targets.contains(&m.to) {
+                        targets.push(m.to);
+                    }
+                }
+                (loc, targets.len())
+            })
+            .collect()
+    }
+
+    /// legal_moves_filtered method gives the legal moves made by pieces of one kind.
+    ///
+    /// #Arguments
+    ///
+    /// kind - a parameter of type PieceKind denoting men or kings.
+    ///
+    /// #Return
+    ///
+    /// Returns the vector of legal moves whose moving piece is of the given kind.
+    pub fn legal_moves_filtered(&self, kind: PieceKind) -> Vec<Move> {
+        self.legal_moves()
+            .into_iter()
+            .filter(|m| matches!(self.piece_at(m.from), Some(piece) if piece.kind() == kind))
+            .collect()
+    }
+
+    /// legal_moves_for_piece method gives the moves of one piece under mandatory capture.
+    ///
+    /// When the rules make capture mandatory and any piece of the current player can jump, only
+    /// jumps are allowed, so a piece without a jump of its own is frozen.
+    ///
+    /// #Arguments
+    ///
+    /// loc - A Coordinate type object denoting location of piece whose moves are desired.
+    ///
+    /// #Return
+    ///
+    /// Returns the vector of moves the piece may make this turn.
+    pub fn legal_moves_for_piece(&self, loc: Coordinate) -> Vec<Move> {
+        self.legal_moves()
+            .into_iter()
+            .filter(|m| m.from == loc)
+            .collect()
+    }
+
+    /// clickable_destinations method gives the squares a clicked piece may move to right now.
+    ///
+    /// #Arguments
+    ///
+    /// loc - A Coordinate type object denoting location of the clicked piece.
+    ///
+    /// #Return
+    ///
+    /// Returns the vector of destination Coordinate, empty when capture is mandatory and a jump
+    /// is forced elsewhere.
+    pub fn clickable_destinations(&self, loc: Coordinate) -> Vec<Coordinate> {
+        self.legal_moves_for_piece(loc)
+            .into_iter()
+            .map(|m| m.to)
+            .collect()
+    }
+
+    /// is_piece_blocked method checks if a piece is immobilized, with no step and no jump.
+    ///
+    /// #Arguments
+    ///
+    /// coord - A Coordinate type object denoting location of the piece.
+    ///
+    /// #Return
+    ///
+    /// Returns a bool value, true when a piece stands at coord and has no valid move.
+    pub fn is_piece_blocked(&self, coord: Coordinate) -> bool {
+        coord.on_board()
+            && self.piece_at(coord).is_some()
+            && self.valid_moves_from(coord).is_empty()
+    }
+
+    /// legal_moves_assuming method gives the moves a piece would have if it were placed on a square.
+    ///
+    /// The piece is placed on a copy of the board, replacing anything on the square, and moves
+    /// as if its own color were to move, whichever side is to move on this board.
+    ///
+    /// #Arguments
+    ///
+    /// coord - A Coordinate type object denoting the square to place the piece on.
+    /// piece - a parameter of type GamePiece to be placed.
+    ///
+    /// #Return
+    ///
+    /// Returns the vector of moves the placed piece could make, empty if coord is off the board
+    /// or a light square pieces can't stand on.
+    pub fn legal_moves_assuming(&self, coord: Coordinate, piece: GamePiece) -> Vec<Move> {
+        if !coord.on_board() {
+            return Vec::new();
+        }
+        let Coordinate(x, y) = coord;
+        if (x + y) % 2 == 0 && !self.rules.orthogonal_moves {
+            return Vec::new();
+        }
+        let mut hypothetical = self.search_copy();
+        hypothetical.board[x][y] = Some(piece);
+        hypothetical.current_turn = piece.color;
+        hypothetical.jumping_piece = None;
+        hypothetical.legal_moves_for_piece(coord)
+    }
+
+    /// is_jump method checks if a move captures a piece.
+    ///
+    /// #Arguments
+    ///
+    /// move_desired - A Move type reference for the move to be checked.
+    ///
+    /// #Return
+    ///
+    /// Returns a bool value denoting if the move jumps over another square.
+    fn is_jump(&self, move_desired: &Move) -> bool {
+        let Coordinate(from_x, from_y) = move_desired.from;
+        let Coordinate(to_x, to_y) = move_desired.to;
+        self.midpiece_coordinate(from_x, from_y, to_x, to_y)
+            .is_some()
+    }
+
+    /// can_jump method checks if the piece at a location may jump to another, ignoring whose turn it is.
+    ///
+    /// #Arguments
+    ///
+    /// from - A Coordinate type object denoting starting location.
+    /// to - A Coordinate type object denoting final location.
+    ///
+    /// #Return
+    ///
+    /// Returns a bool value telling if the jump is valid.
+    pub fn can_jump(&self, from: Coordinate, to: Coordinate) -> bool {
+        let hop = Move { from, to };
+        from.on_board() && self.valid_moves_from(from).contains(&hop) && self.is_jump(&hop)
+    }
+
+    /// can_step method checks if the piece at a location may make a simple move to another,
+    /// ignoring whose turn it is.
+    ///
+    /// #Arguments
+    ///
+    /// from - A Coordinate type object denoting starting location.
+    /// to - A Coordinate type object denoting final location.
+    ///
+    /// #Return
+    ///
+    /// Returns a bool value telling if the move is valid.
+    pub fn can_step(&self, from: Coordinate, to: Coordinate) -> bool {
+        let step = Move { from, to };
+        from.on_board() && self.valid_moves_from(from).contains(&step) && !self.is_jump(&step)
+    }
+
+    /// jumps_from method gives the valid jumps from a particular location on the board.
+    ///
+    /// #Arguments
+    ///
+    /// loc - A Coordinate type object denoting location of the jumping piece.
+    ///
+    /// #Return
+    ///
+    /// Returns the vector of valid jumps.
+    fn jumps_from(&self, loc: Coordinate) -> Vec<Move> {
+        self.valid_moves_from(loc)
+            .into_iter()
+            .filter(|m| self.is_jump(m))
+            .collect()
+    }
+
+    /// has_jump_from method checks if the piece at a location can jump.
+    ///
+    /// #Arguments
+    ///
+    /// loc - A Coordinate type object denoting location of the piece.
+    ///
+    /// #Return
+    ///
+    /// Returns a bool value denoting if a jump is available.
+    fn has_jump_from(&self, loc: Coordinate) -> bool {
+        let Coordinate(x, y) = loc;
+        match self.board[x][y] {
+            Some(piece) if self.is_flying(&piece) => loc
+                .diagonal_targets_from()
+                .any(|coord| self.valid_jump(&piece, &loc, &coord)),
+            Some(piece) if self.rules.orthogonal_moves => loc
+                .orthogonal_jump_targets_from()
+                .any(|coord| self.valid_jump(&piece, &loc, &coord)),
+            Some(piece) => loc
+                .jump_targets_from()
+                .any(|coord| self.valid_jump(&piece, &loc, &coord)),
+            None => false,
+        }
+    }
+
+    /// capture_length method counts the pieces taken by the longest capture starting with a hop.
+    ///
+    /// #Arguments
+    ///
+    /// move_desired - A Move type reference for the first hop.
+    ///
+    /// #Return
+    ///
+    /// Returns an usize value denoting the pieces captured, 0 if the move isn't a jump.
+    fn capture_length(&self, move_desired: &Move) -> usize {
+        if !self.is_jump(move_desired) {
+            return 0;
+        }
+        let mut next = self.search_copy();
+        let (_, promotes) = next.apply_hop(move_desired);
+        if !self.rules.multi_jump || (promotes && self.rules.crowning_ends_capture) {
+            return 1;
+        }
+        1 + next
+            .jumps_from(move_desired.to)
+            .iter()
+            .map(|m| next.capture_length(m))
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// valid_moves_from method gives all the valid moves from a particular location on the board.
+    ///
+    /// #Arguments
+    ///
+    /// loc - A Coordinate type object denoting location of piece whose valid moves are desired.
+    ///
+    /// #Return
+    ///
+    /// Returns the vector of valid moves.
+    fn valid_moves_from(&self, loc: Coordinate) -> Vec<Move> {
+        let Coordinate(x, y) = loc;
+        if let Some(piece) = self.board[x][y] {
+            let (jump_targets, move_targets) = if self.is_flying(&piece) {
+                (
+                    loc.diagonal_targets_from().collect::<Vec<Coordinate>>(),
+                    loc.diagonal_targets_from().collect::<Vec<Coordinate>>(),
+                )
+            } else if self.rules.orthogonal_moves {
+                (
+                    loc.orthogonal_jump_targets_from().collect(),
+                    loc.orthogonal_move_targets_from().collect(),
+                )
+            } else {
+                (
+                    loc.jump_targets_from().collect(),
+                    loc.move_targets_from().collect(),
+                )
+            };
+            let mut jumps = jump_targets
+                .into_iter()
+                .filter(|coord| self.valid_jump(&piece, &loc, coord))
+                .map(|coord| Move {
+                    from: loc,
+                    to: coord,
+                })
+                .collect::<Vec<Move>>();
+            let mut moves = move_targets
+                .into_iter()
+                .filter(|coord| self.valid_move(&piece, &loc, coord))
+                .map(|coord| Move {
+                    from: loc,
+                    to: coord,
+                })
+                .collect::<Vec<Move>>();
+            jumps.append(&mut moves);
+            jumps
+        } else {
+            Vec::new()
+        }
+    }
+
+    /// midpiece_coordinate method gives the location of piece in between the jump.
+    ///
+    /// #Arguments
+    ///
+    /// from_x - an usize parameter for x coordinate of starting location.
+    /// from_y - an usize parameter for y coordinate of starting location.
+    /// to_x - an usize parameter for x coordinate of final location.
+    /// to_y - an usize parameter for y coordinate of final location.
+    ///
+    /// #Return
+    ///
+    /// Returns the Coordinate of mid piece wrapped in Option, None unless exactly one piece
+    /// stands on the diagonal between the two locations.
+    fn midpiece_coordinate(
+        &self,
+        from_x: usize,
+        from_y: usize,
+        to_x: usize,
+        to_y: usize,
+    ) -> Option<Coordinate> {
+        let between = if self.rules.orthogonal_moves {
+            orthogonal_squares_between(Coordinate(from_x, from_y), Coordinate(to_x, to_y))?
+        } else {
+            Coordinate(from_x, from_y).squares_between(Coordinate(to_x, to_y))?
+        };
+        let mut occupied = between
+            .into_iter()
+            .filter(|&Coordinate(x, y)| self.board[x][y].is_some());
+        match (occupied.next(), occupied.next()) {
+            (Some(coord), None) => Some(coord),
+            _ => None,
+        }
+    }
+
+    /// midpiece method gives the piece in between the jump.
+    ///
+    /// #Arguments
+    ///
+    /// from_x - an usize parameter for x coordinate of starting location.
+    /// from_y - an usize parameter for y coordinate of starting location.
+    /// to_x - an usize parameter for x coordinate of final location.
+    /// to_y - an usize parameter for y coordinate of final location.
+    ///
+    /// #Return
+    ///
+    /// Returns the GamePiece in middle wrapped in Option.
+    fn midpiece(
+        &self,
+        from_x: usize,
+        from_y: usize,
+        to_x: usize,
+        to_y: usize,
+    ) -> Option<GamePiece> {
+        match self.midpiece_coordinate(from_x, from_y, to_x, to_y) {
+            Some(Coordinate(coord_x, coord_y)) => self.board[coord_x][coord_y],
+            None => None,
+        }
+    }
+
+    /// is_flying method checks if a piece moves any distance along a diagonal.
+    ///
+    /// #Arguments
+    ///
+    /// piece - A GamePiece type reference for the piece to be checked.
+    ///
+    /// #Return
+    ///
+    /// Returns a bool value, true for kings under flying_kings when moves are diagonal.
+    fn is_flying(&self, piece: &GamePiece) -> bool {
+        piece.crowned && self.rules.flying_kings && !self.rules.orthogonal_moves
+    }
+
+    /// valid_jump method check if the jump is valid from a location on board to other.
+    ///
+    /// #Arguments
+    ///
+    /// moving_piece - A GamePiece type reference for the Piece making the jump.
+    /// from - A Coordinate type reference denoting starting location.
+    /// to - A Coordinate type reference denoting final location.
+    ///
+    /// #Return
+    ///
+    /// Returns a bool value telling if the jump is valid.
+    fn valid_jump(&self, moving_piece: &GamePiece, from: &Coordinate, to: &Coordinate) -> bool {
+        if !to.on_board() || !from.on_board() {
+            false
+        } else if self.piece_at(*to).is_some() {
+            // a jump can't land on another piece, friendly or not
+            false
+        } else {
+            let Coordinate(from_x, from_y) = *from;
+            let Coordinate(to_x, to_y) = *to;
+
+            let distance = if self.rules.orthogonal_moves {
+                from_x.abs_diff(to_x) + from_y.abs_diff(to_y)
+            } else {
+                from_x.abs_diff(to_x)
+            };
+            if !self.is_flying(moving_piece) && distance != 2 {
+                return false;
+            }
+            let sideways = self.rules.orthogonal_moves && from_y == to_y;
+            let backward = !sideways && !is_forward(moving_piece.color, from_y, to_y);
+            if !moving_piece.crowned && !self.rules.men_capture_backward && backward {
+                return false;
+            }
+
+            if let Some(coord) = self.midpiece_coordinate(from_x, from_y, to_x, to_y) {
+                if self.pending_captures.contains(&coord) {
+                    return false;
+                }
+            }
+            let midpiece = self.midpiece(from_x, from_y, to_x, to_y);
+            matches!(midpiece, Some(piece) if piece.color != moving_piece.color)
+        }
+    }
+
+    /// valid_move method check if the move is valid from a location on board to other.
+    ///
+    /// #Arguments
+    ///
+    /// moving_piece - A GamePiece type reference for the Piece making the move.
+    /// from - A Coordinate type reference denoting starting location.
+    /// to - A Coordinate type reference denoting final location.
+    ///
+    /// #Return
+    ///
+    /// Returns a bool value telling if the move is valid.
+    fn valid_move(&self, moving_piece: &GamePiece, from: &Coordinate, to: &Coordinate) -> bool {
+        if !to.on_board() || !from.on_board() {
+            false
+        } else if self.rules.orthogonal_moves {
+            let Coordinate(from_x, from_y) = *from;
+            let Coordinate(to_x, to_y) = *to;
+            self.board[to_x][to_y].is_none()
+                && from_x.abs_diff(to_x) + from_y.abs_diff(to_y) == 1
+                && (moving_piece.crowned
+                    || from_y == to_y
+                    || is_forward(moving_piece.color, from_y, to_y))
+        } else if self.is_flying(moving_piece) {
+            let Coordinate(to_x, to_y) = *to;
+            self.board[to_x][to_y].is_none()
+                && matches!(from.squares_between(*to), Some(between)
+                    if between.iter().all(|&Coordinate(x, y)| self.board[x][y].is_none()))
+        } else {
+            let Coordinate(to_x, to_y) = *to;
+            if let Some(_piece) = self.board[to_x][to_y] {
+                false
+            } else {
+                let Coordinate(_from_x, from_y) = *from;
+                let mut valid = false;
+                if to_y > from_y && moving_piece.color == PieceColor::White {
+                    // white moves down
+                    valid = true;
+                }
+                if to_y < from_y && moving_piece.color == PieceColor::Black {
+                    // black moves up
+                    valid = true;
+                }
+                if to_y > from_y && moving_piece.color == PieceColor::Black && moving_piece.crowned
+                {
+                    // crowned black move down
+                    valid = true;
+                }
+                if to_y < from_y && moving_piece.color == PieceColor::White && moving_piece.crowned
+                {
+                    // crowned white move up
+                    valid = true;
+                }
+                valid
+            }
+        }
+    }
+}
+
+impl Default for GameEngine {
+    /// default method creates a new game with the default rules.
+    ///
+    /// #Return
+    ///
+    /// Returns the instance of type GameEngine.
+    fn default() -> GameEngine {
+        GameEngine::new()
+    }
+}
+
+/// is_forward function checks if a vertical move goes toward the opponent's side.
+///
+/// #Arguments
+///
+/// color - a parameter of type PieceColor denoting color of the moving piece.
+/// from_y - an usize parameter for y coordinate of starting location.
+/// to_y - an usize parameter for y coordinate of final location.
+///
+/// #Return
+///
+/// Returns a bool value, black moving up the board and white moving down.
+fn is_forward(color: PieceColor, from_y: usize, to_y: usize) -> bool {
+    match color {
+        PieceColor::Black => to_y < from_y,
+        PieceColor::White => to_y > from_y,
+    }
+}
+
+/// orthogonal_squares_between function gives the squares strictly between two points of a rank or file.
+///
+/// #Arguments
+///
+/// from - a parameter of type Coordinate denoting one end of the line.
+/// to - a parameter of type Coordinate denoting the other end of the line.
+///
+/// #Return
+///
+/// Returns a vector of Coordinate wrapped in Option, None if the points don't share a rank or file.
+fn orthogonal_squares_between(from: Coordinate, to: Coordinate) -> Option<Vec<Coordinate>> {
+    let Coordinate(from_x, from_y) = from;
+    let Coordinate(to_x, to_y) = to;
+    if from_x == to_x && from_y != to_y {
+        Some(
+            (from_y.min(to_y) + 1..from_y.max(to_y))
+                .map(|y_coord| Coordinate(from_x, y_coord))
+                .collect(),
+        )
+    } else if from_y == to_y && from_x != to_x {
+        Some(
+            (from_x.min(to_x) + 1..from_x.max(to_x))
+                .map(|x_coord| Coordinate(x_coord, from_y))
+                .collect(),
+        )
+    } else {
+        None
+    }
+}
+
+/// zobrist_key function gives the pseudo random key for a piece on a square or the side to move.
+///
+/// #Arguments
+///
+/// index - an u64 parameter identifying the feature being hashed.
+///
+/// #Return
+///
+/// Returns an u64 key, mixed with splitmix64 so no table has to be stored.
+fn zobrist_key(index: u64) -> u64 {
+    splitmix64(index.wrapping_mul(SPLITMIX_GAMMA))
+}
+
+/// splitmix64 function scrambles a number into a pseudo random one.
+///
+/// #Arguments
+///
+/// seed - an u64 parameter to be scrambled.
+///
+/// #Return
+///
+/// Returns the pseudo random u64 value.
+pub(crate) fn splitmix64(seed: u64) -> u64 {
+    let mut z = seed.wrapping_add(SPLITMIX_GAMMA);
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^ (z >> 31)
+}
+
+/// dark_squares function gives the 32 squares pieces stand on in diagonal play, in row-major
+/// order.
+///
+/// #Return
+///
+/// Returns the iterator over Coordinate type objects of the dark squares.
+fn dark_squares() -> impl Iterator<Item = Coordinate> {
+    (0..8)
+        .flat_map(|y_coord| (0..8).map(move |x_coord| Coordinate(x_coord, y_coord)))
+        .filter(|&Coordinate(x_coord, y_coord)| (x_coord + y_coord) % 2 == 1)
+}
+
+/// board_diff function lists the squares which differ between two boards.
+///
+/// #Arguments
+///
+/// before - a reference of the board to be compared from.
+/// after - a reference of the board to be compared with.
+///
+/// #Return
+///
+/// Returns the vector of changed Coordinate with the piece it holds in after, column by column.
+fn board_diff(
+    before: &[[Option<GamePiece>; BOARD_SIZE]; BOARD_SIZE],
+    after: &[[Option<GamePiece>; BOARD_SIZE]; BOARD_SIZE],
+) -> Vec<(Coordinate, Option<GamePiece>)> {
+    let mut changes = Vec::new();
+    for col in 0..BOARD_SIZE {
+        for row in 0..BOARD_SIZE {
+            if before[col][row] != after[col][row] {
+                changes.push((Coordinate(col, row), after[col][row]));
+            }
+        }
+    }
+    changes
+}
+
+/// square_name function gives the algebraic name of a square.
+///
+/// #Arguments
+///
+/// coord - a parameter of type Coordinate to be named.
+///
+/// #Return
+///
+/// Returns the String holding the file letter and rank number, "a1" being Coordinate(0, 7), or
+/// "??" for a square off the board.
+fn square_name(coord: Coordinate) -> String {
+    if !coord.on_board() {
+        return "??".to_string();
+    }
+    let Coordinate(x, y) = coord;
+    format!("{}{}", (b'a' + x as u8) as char, 8 - y)
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::board::{Coordinate, GamePiece, Move, Orientation, PieceColor, PieceKind};
+    use super::super::rules::{Rules, RulesBuilder};
+    use super::{
+        dark_squares, BoardError, DecodeError, GameEngine, GameObserver, GameReport, GameStatus,
+        JumpState, MoveError, Outcome, BOARD_SIZE, DRAW_PLY_LIMIT, ENCODED_LEN,
+        ENCODED_LEN_ALL_SQUARES, KING_ADVANCEMENT, KING_THREAT_PENALTY, ORIENTATION,
+    };
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+    use std::collections::HashSet;
+
+    fn empty_engine(rules: Rules) -> GameEngine {
+        let mut engine = GameEngine::with_rules(rules);
+        engine.board = [[None; BOARD_SIZE]; BOARD_SIZE];
+        engine
+    }
+
+    #[test]
+    fn should_crown_success() {
+        let engine = GameEngine::new();
+        let black = GamePiece::new(PieceColor::Black);
+        let res = engine.should_crown(black, Coordinate(3, 0));
+        assert!(res);
+    }
+
+    #[test]
+    #[allow(clippy::bool_assert_comparison)]
+    fn should_crown_failure() {
+        let engine = GameEngine::new();
+        let black = GamePiece::new(PieceColor::Black);
+        let res_no_crown = engine.should_crown(black, Coordinate(5, 2));
+        assert_eq!(res_no_crown, false);
+    }
+
+    #[test]
+    fn crown_row_success() {
+        assert_eq!(PieceColor::Black.crown_row(10, Orientation::BlackHigh), 0);
+        assert_eq!(PieceColor::White.crown_row(10, Orientation::BlackHigh), 9);
+        assert_eq!(PieceColor::Black.crown_row(10, Orientation::BlackLow), 9);
+        assert_eq!(PieceColor::White.crown_row(10, Orientation::BlackLow), 0);
+
+        // men moving onto the rows crown_row gives are crowned
+        let mut engine = empty_engine(Rules::default());
+        let white_row = PieceColor::White.crown_row(BOARD_SIZE, ORIENTATION);
+        let black_row = PieceColor::Black.crown_row(BOARD_SIZE, ORIENTATION);
+        engine.board[1][black_row + 1] = Some(GamePiece::new(PieceColor::Black));
+        engine.board[6][white_row - 1] = Some(GamePiece::new(PieceColor::White));
+
+        let result = engine
+            .move_piece(&Move::new((1, black_row + 1), (0, black_row)))
+            .unwrap();
+        assert!(result.crowned);
+        let result = engine
+            .move_piece(&Move::new((6, white_row - 1), (7, white_row)))
+            .unwrap();
+        assert!(result.crowned);
+        assert!(engine.is_crowned(Coordinate(7, white_row)));
+    }
+
+    #[test]
+    fn crown_success() {
+        let mut engine = GameEngine::new();
+        engine.initialize_pieces();
+        let crowned = engine.crown_piece(Coordinate(1, 0));
+        assert!(crowned);
+    }
+
+    #[test]
+    #[allow(clippy::bool_assert_comparison)]
+    fn crown_failure() {
+        let mut engine = GameEngine::new();
+        engine.initialize_pieces();
+        let crowned = engine.crown_piece(Coordinate(2, 0));
+        assert_eq!(crowned, false);
+    }
+
+    #[test]
+    fn is_crown_success() {
+        let mut engine = GameEngine::new();
+        engine.initialize_pieces();
+        let crowned = engine.crown_piece(Coordinate(1, 0));
+        assert!(crowned);
+        assert!(engine.is_crowned(Coordinate(1, 0)));
+    }
+
+    #[test]
+    #[allow(clippy::bool_assert_comparison)]
+    fn is_crown_failure() {
+        let mut engine = GameEngine::new();
+        engine.initialize_pieces();
+        assert_eq!(engine.is_crowned(Coordinate(1, 0)), false);
+    }
+
+    #[test]
+    fn advance_turn() {
+        let mut engine = GameEngine::new();
+        engine.advance_turn();
+        assert_eq!(engine.current_turn(), PieceColor::White);
+    }
+
+    #[test]
+    fn move_count_success() {
+        let mut engine = GameEngine::new();
+        engine.advance_turn();
+        engine.advance_turn();
+        assert_eq!(engine.move_count(), 2);
+    }
+
+    #[test]
+    fn set_move_count_success() {
+        let mut engine = GameEngine::new();
+        assert_eq!(engine.full_move_number(), 1);
+
+        engine.set_move_count(41);
+        assert_eq!(engine.move_count(), 41);
+        assert_eq!(engine.full_move_number(), 21);
+
+        engine.move_piece(&Move::new((0, 5), (1, 4))).unwrap();
+        assert_eq!(engine.move_count(), 42);
+        assert_eq!(engine.full_move_number(), 22);
+    }
+
+    #[test]
+    fn move_targets_success() {
+        let coord = Coordinate(0, 5);
+        let targets = coord.move_targets_from().collect::<Vec<Coordinate>>();
+        assert_eq!(targets, [Coordinate(1, 6), Coordinate(1, 4)]);
+    }
+
+    #[test]
+    fn valid_from_success() {
+        let coord_1 = Coordinate(0, 5);
+        let coord_2 = Coordinate(2, 5);
+
+        let mut engine = GameEngine::new();
+        engine.initialize_pieces();
+        let move_1 = engine.valid_moves_from(coord_1);
+        let move_2 = engine.valid_moves_from(coord_2);
+        assert_eq!(
+            move_1,
+            [Move {
+                from: Coordinate(0, 5),
+                to: Coordinate(1, 4),
+            }]
+        );
+        assert_eq!(
+            move_2,
+            [
+                Move {
+                    from: Coordinate(2, 5),
+                    to: Coordinate(3, 4),
+                },
+                Move {
+                    from: Coordinate(2, 5),
+                    to: Coordinate(1, 4),
+                }
+            ]
+        );
+    }
+
+    #[test]
+    fn valid_from_failure() {
+        let coord = Coordinate(1, 0);
+        let mut engine = GameEngine::new();
+        engine.initialize_pieces();
+        let move_res = engine.valid_moves_from(coord);
+        assert_eq!(move_res, vec![]);
+    }
+
+    #[test]
+    fn legal_moves_black_success() {
+        let mut engine = GameEngine::new();
+        engine.initialize_pieces();
+        let moves = engine.legal_moves();
+        assert_eq!(
+            moves,
+            [
+                Move {
+                    from: Coordinate(0, 5),
+                    to: Coordinate(1, 4),
+                },
+                Move {
+                    from: Coordinate(2, 5),
+                    to: Coordinate(3, 4),
+                },
+                Move {
+                    from: Coordinate(2, 5),
+                    to: Coordinate(1, 4),
+                },
+                Move {
+                    from: Coordinate(4, 5),
+                    to: Coordinate(5, 4),
+                },
+                Move {
+                    from: Coordinate(4, 5),
+                    to: Coordinate(3, 4),
+                },
+                Move {
+                    from: Coordinate(6, 5),
+                    to: Coordinate(7, 4),
+                },
+                Move {
+                    from: Coordinate(6, 5),
+                    to: Coordinate(5, 4),
+                }
+            ]
+        );
+    }
+
+    #[test]
+    fn legal_moves_white_success() {
+        let mut engine = GameEngine::new();
+        engine.initialize_pieces();
+        engine.advance_turn();
+        let moves = engine.legal_moves();
+        assert_eq!(
+            moves,
+            [
+                Move {
+                    from: Coordinate(1, 2),
+                    to: Coordinate(0, 3),
+                },
+                Move {
+                    from: Coordinate(1, 2),
+                    to: Coordinate(2, 3),
+                },
+                Move {
+                    from: Coordinate(3, 2),
+                    to: Coordinate(2, 3),
+                },
+                Move {
+                    from: Coordinate(3, 2),
+                    to: Coordinate(4, 3),
+                },
+                Move {
+                    from: Coordinate(5, 2),
+                    to: Coordinate(4, 3),
+                },
+                Move {
+                    from: Coordinate(5, 2),
+                    to: Coordinate(6, 3),
+                },
+                Move {
+                    from: Coordinate(7, 2),
+                    to: Coordinate(6, 3),
+                }
+            ]
+        );
+    }
+
+    #[test]
+    fn jump_targets_success() {
+        let coord = Coordinate(3, 3);
+        let targets = coord.jump_targets_from().collect::<Vec<Coordinate>>();
+        assert_eq!(
+            targets,
+            [
+                Coordinate(5, 1),
+                Coordinate(5, 5),
+                Coordinate(1, 1),
+                Coordinate(1, 5)
+            ]
+        );
+    }
+
+    #[test]
+    fn jump_moves_validation_success() {
+        let mut engine = GameEngine::new();
+        engine.initialize_pieces();
+        engine.board[1][4] = Some(GamePiece::new(PieceColor::White));
+        let moves = engine.legal_moves();
+        assert_eq!(
+            moves,
+            [
+                Move {
+                    from: Coordinate(0, 5),
+                    to: Coordinate(2, 3),
+                },
+                Move {
+                    from: Coordinate(2, 5),
+                    to: Coordinate(0, 3)
+                },
+                Move {
+                    from: Coordinate(2, 5),
+                    to: Coordinate(3, 4)
+                },
+                Move {
+                    from: Coordinate(4, 5),
+                    to: Coordinate(5, 4)
+                },
+                Move {
+                    from: Coordinate(4, 5),
+                    to: Coordinate(3, 4)
+                },
+                Move {
+                    from: Coordinate(6, 5),
+                    to: Coordinate(7, 4)
+                },
+                Move {
+                    from: Coordinate(6, 5),
+                    to: Coordinate(5, 4)
+                }
+            ]
+        );
+    }
+
+    #[test]
+    #[allow(clippy::nonminimal_bool)]
+    fn test_basic_move_success() {
+        let mut engine = GameEngine::new();
+        engine.initialize_pieces();
+        let res = engine.move_piece(&Move::new((0, 5), (1, 4)));
+        assert!(res.is_ok());
+
+        let old = engine.board[0][5];
+        let new = engine.board[1][4];
+        assert_eq!(old, None);
+        assert_eq!(
+            new,
+            Some(GamePiece {
+                color: PieceColor::Black,
+                crowned: false
+            })
+        );
+
+        // fail to perform illegal move
+        let res = engine.move_piece(&Move::new((1, 4), (2, 4))); // can't move horiz
+        assert!(!res.is_ok());
+        assert_eq!(engine.board[2][4], None);
+    }
+
+    #[test]
+    fn move_piece_current_turn_success() {
+        let mut engine = GameEngine::new();
+        let res = engine.move_piece(&Move::new((0, 5), (1, 4))).unwrap();
+        assert_eq!(res.current_turn, PieceColor::White);
+        assert_eq!(res.current_turn, engine.current_turn());
+
+        let res = engine.move_piece(&Move::new((1, 2), (2, 3))).unwrap();
+        assert_eq!(res.current_turn, PieceColor::Black);
+    }
+
+    #[test]
+    fn bytes_round_trip_success() {
+        let mut engine = GameEngine::new();
+        engine.move_piece(&Move::new((0, 5), (1, 4))).unwrap();
+        engine.crown_piece(Coordinate(1, 0));
+
+        let bytes = engine.to_bytes();
+        assert_eq!(bytes.len(), ENCODED_LEN);
+
+        let decoded = GameEngine::from_bytes(&bytes).unwrap();
+        assert_eq!(decoded.board, engine.board);
+        assert_eq!(decoded.current_turn(), PieceColor::White);
+        assert!(decoded.is_crowned(Coordinate(1, 0)));
+    }
+
+    #[test]
+    fn bytes_round_trip_turkish() {
+        let rules = RulesBuilder::new().orthogonal_moves(true).build();
+        let mut engine = GameEngine::with_rules(rules);
+        engine.initialize_turkish_pieces();
+        engine.crown_piece(Coordinate(0, 6));
+
+        let bytes = engine.to_bytes();
+        assert_eq!(bytes.len(), ENCODED_LEN_ALL_SQUARES);
+
+        let mut decoded = GameEngine::from_bytes(&bytes).unwrap();
+        assert_eq!(decoded.board, engine.board);
+        assert_eq!(decoded.piece_count(PieceColor::Black), 16);
+        assert!(decoded.is_crowned(Coordinate(0, 6)));
+        assert!(decoded.rules().orthogonal_moves);
+        decoded.reset_game();
+        assert_eq!(decoded.piece_count(PieceColor::White), 16);
+
+        // a piece on a light square changes the encoding
+        let mut moved = engine.clone();
+        moved.board[0][6] = None;
+        assert_ne!(moved.board_checksum(), engine.board_checksum());
+    }
+
+    #[test]
+    fn turkish_piece_queries_success() {
+        let rules = RulesBuilder::new().orthogonal_moves(true).build();
+        let mut engine = GameEngine::with_rules(rules);
+        engine.initialize_turkish_pieces();
+
+        let black = engine.find_pieces(|piece| piece.color == PieceColor::Black);
+        assert_eq!(black.len(), 16);
+        assert_eq!(engine.mobility_map().len(), 16);
+        // eight men a row up and eight two rows up
+        assert_eq!(engine.advancement_score(PieceColor::Black), 24);
+        assert!(!engine.back_row_intact(PieceColor::Black));
+    }
+
+    #[test]
+    fn bytes_truncated_failure() {
+        let bytes = GameEngine::new().to_bytes();
+        let res = GameEngine::from_bytes(&bytes[..ENCODED_LEN - 1]);
+        assert_eq!(res.err(), Some(DecodeError::Truncated));
+    }
+
+    #[test]
+    fn plies_since_progress_success() {
+        let mut engine = GameEngine::new();
+        engine.move_piece(&Move::new((6, 5), (7, 4))).unwrap();
+        engine.move_piece(&Move::new((7, 2), (6, 3))).unwrap();
+        assert_eq!(engine.plies_since_progress(), 2);
+
+        engine.board[1][4] = Some(GamePiece::new(PieceColor::White));
+        engine.move_piece(&Move::new((0, 5), (2, 3))).unwrap();
+        assert_eq!(engine.plies_since_progress(), 0);
+
+        engine.move_piece(&Move::new((1, 2), (0, 3))).unwrap();
+        assert_eq!(engine.plies_since_progress(), 1);
+    }
+
+    #[test]
+    fn piece_at_success() {
+        let mut engine = GameEngine::new();
+        assert_eq!(engine.piece_at(Coordinate(0, 0)), None);
+        match engine.piece_at(Coordinate(1, 0)) {
+            Some(piece) => assert!(!piece.crowned),
+            None => panic!("expected a piece at (1, 0)"),
+        }
+
+        engine.crown_piece(Coordinate(1, 0));
+        assert_eq!(
+            engine.piece_at(Coordinate(1, 0)).map(|piece| piece.crowned),
+            Some(true)
+        );
+    }
+
+    #[test]
+    fn crown_all_success() {
+        let mut engine = GameEngine::new();
+        assert_eq!(engine.king_count(PieceColor::Black), 0);
+
+        engine.crown_all();
+        assert_eq!(engine.king_count(PieceColor::Black), 12);
+        assert_eq!(engine.king_count(PieceColor::White), 12);
+        assert_eq!(
+            engine.king_count(PieceColor::Black) + engine.king_count(PieceColor::White),
+            engine.piece_count(PieceColor::Black) + engine.piece_count(PieceColor::White)
+        );
+    }
+
+    #[test]
+    fn backward_step_failure() {
+        let mut engine = GameEngine::new();
+        engine.move_piece(&Move::new((0, 5), (1, 4))).unwrap();
+        engine.move_piece(&Move::new((1, 2), (2, 3))).unwrap();
+
+        // an uncrowned black piece can't step back down the board
+        let res = engine.move_piece(&Move::new((1, 4), (0, 5)));
+        assert!(res.is_err());
+        assert_eq!(engine.board[0][5], None);
+        assert_eq!(engine.current_turn(), PieceColor::Black);
+
+        // nor can an uncrowned white piece step back up
+        engine.move_piece(&Move::new((6, 5), (7, 4))).unwrap();
+        let res = engine.move_piece(&Move::new((2, 3), (1, 2)));
+        assert!(res.is_err());
+        assert_eq!(engine.board[1][2], None);
+    }
+
+    #[test]
+    fn mobility_map_success() {
+        let mut engine = GameEngine::new();
+        let map = engine.mobility_map();
+        assert_eq!(map.len(), 12);
+        assert_eq!(map[&Coordinate(0, 5)], 1);
+        assert_eq!(map[&Coordinate(1, 6)], 0);
+        assert_eq!(map.values().sum::<usize>(), engine.legal_moves().len());
+
+        engine.move_piece(&Move::new((2, 5), (3, 4))).unwrap();
+        engine.move_piece(&Move::new((1, 2), (2, 3))).unwrap();
+        let map = engine.mobility_map();
+        assert_eq!(map.values().sum::<usize>(), engine.legal_moves().len());
+    }
+
+    #[test]
+    fn move_piece_off_board_failure() {
+        let mut engine = GameEngine::new();
+        assert_eq!(
+            engine.move_piece(&Move::new((0, 5), (8, 8))),
+            Err(MoveError::OffBoard)
+        );
+        assert_eq!(
+            engine.move_piece(&Move::new((9, 5), (1, 4))),
+            Err(MoveError::OffBoard)
+        );
+        assert_eq!(engine.move_count(), 0);
+    }
+
+    #[test]
+    fn move_hash_set_success() {
+        let engine = GameEngine::new();
+        let moves: HashSet<Move> = engine.legal_moves().into_iter().collect();
+        assert_eq!(moves.len(), engine.legal_moves().len());
+        assert!(moves.contains(&Move::new((0, 5), (1, 4))));
+        assert!(!moves.contains(&Move::new((0, 5), (0, 4))));
+    }
+
+    #[test]
+    fn move_piece_legality_regression() {
+        let mut engine = GameEngine::new();
+        assert_eq!(
+            engine.move_piece(&Move::new((0, 5), (0, 4))).unwrap_err(),
+            MoveError::IllegalMove
+        );
+        assert_eq!(
+            engine.move_piece(&Move::new((1, 2), (0, 3))).unwrap_err(),
+            MoveError::IllegalMove
+        );
+        assert!(engine.move_piece(&Move::new((0, 5), (1, 4))).is_ok());
+        assert!(engine.move_piece(&Move::new((1, 2), (0, 3))).is_ok());
+        assert_eq!(engine.current_turn(), PieceColor::Black);
+    }
+
+    #[test]
+    fn pack_round_trip_success() {
+        let packed = Move::new((0, 5), (1, 4)).pack();
+        assert_eq!(packed >> 6, 40);
+        assert_eq!(packed & 0x3f, 33);
+
+        for from in dark_squares() {
+            for to in dark_squares() {
+                let m = Move { from, to };
+                assert_eq!(Move::unpack(m.pack()), Some(m));
+            }
+        }
+        assert_eq!(Move::unpack(1 << 12), None);
+    }
+
+    #[test]
+    fn square_number_round_trip_success() {
+        for number in 1..=32 {
+            let coord = Coordinate::from_square_number(number, 8).unwrap();
+            assert!(coord.on_board());
+            assert_eq!(coord.to_square_number(8), Some(number));
+        }
+        assert_eq!(Coordinate::from_square_number(1, 8), Some(Coordinate(6, 7)));
+        assert_eq!(
+            Coordinate::from_square_number(32, 8),
+            Some(Coordinate(1, 0))
+        );
+        assert_eq!(Coordinate::from_square_number(0, 8), None);
+        assert_eq!(Coordinate::from_square_number(33, 8), None);
+    }
+
+    #[test]
+    fn move_notation_success() {
+        let mut engine = GameEngine::new();
+        engine.move_notation("11-15").unwrap();
+        engine.move_notation("22-18").unwrap();
+        let result = engine.move_notation("15x22").unwrap();
+        assert_eq!(result.move_made, Move::new((3, 4), (5, 2)));
+        assert_eq!(
+            result.captured_pieces,
+            vec![(Coordinate(4, 3), GamePiece::new(PieceColor::White))]
+        );
+        assert_eq!(
+            engine.piece_at_square(22),
+            Some(GamePiece::new(PieceColor::Black))
+        );
+    }
+
+    #[test]
+    fn move_notation_failure() {
+        let mut engine = GameEngine::new();
+        for notation in ["", "11", "11-", "11-15-19", "0-4", "11/15", "a3-b4"].iter() {
+            assert_eq!(
+                engine.move_notation(notation),
+                Err(MoveError::InvalidNotation)
+            );
+        }
+        assert_eq!(engine.move_notation("11x15"), Err(MoveError::IllegalMove));
+        assert_eq!(engine.move_notation("11-18"), Err(MoveError::IllegalMove));
+        assert_eq!(engine.move_count(), 0);
+    }
+
+    #[test]
+    fn piece_at_square_success() {
+        let engine = GameEngine::new();
+        assert_eq!(
+            engine.piece_at_square(1),
+            Some(GamePiece::new(PieceColor::Black))
+        );
+        assert_eq!(
+            engine.piece_at_square(32),
+            Some(GamePiece::new(PieceColor::White))
+        );
+        assert_eq!(engine.piece_at_square(15), None);
+        assert_eq!(engine.piece_at_square(0), None);
+        assert_eq!(engine.piece_at_square(33), None);
+    }
+
+    #[test]
+    fn square_number_light_square_failure() {
+        assert_eq!(Coordinate(0, 0).to_square_number(8), None);
+        assert_eq!(Coordinate(7, 7).to_square_number(8), None);
+        assert_eq!(Coordinate(8, 1).to_square_number(8), None);
+    }
+
+    #[test]
+    fn zobrist_hash_success() {
+        let mut engine = GameEngine::new();
+        let start = engine.zobrist_hash();
+        assert_eq!(start, GameEngine::new().zobrist_hash());
+
+        engine.move_piece(&Move::new((0, 5), (1, 4))).unwrap();
+        assert_ne!(engine.zobrist_hash(), start);
+
+        // the same pieces with the other side to move hash differently
+        let mut other = engine.clone();
+        other.advance_turn();
+        assert_ne!(engine.zobrist_hash(), other.zobrist_hash());
+
+        // a piece already jumped under deferred removal can't be jumped again
+        let mut engine = empty_engine(Rules::international());
+        engine.board[1][2] = Some(GamePiece::crowned(GamePiece::new(PieceColor::Black)));
+        engine.board[3][4] = Some(GamePiece::new(PieceColor::White));
+        engine.jumping_piece = Some(Coordinate(1, 2));
+        let mut jumped = engine.clone();
+        jumped.pending_captures.push(Coordinate(3, 4));
+        assert_ne!(engine.zobrist_hash(), jumped.zobrist_hash());
+    }
+
+    #[test]
+    fn legal_moves_unique() {
+        for rules in &[Rules::default(), Rules::american(), Rules::international()] {
+            let mut engine = empty_engine(*rules);
+            let king = GamePiece::crowned(GamePiece::new(PieceColor::Black));
+            engine.board[0][7] = Some(king);
+            engine.board[7][0] = Some(king);
+            engine.board[0][1] = Some(king);
+            engine.board[7][6] = Some(GamePiece::new(PieceColor::Black));
+            engine.board[1][6] = Some(GamePiece::new(PieceColor::White));
+            engine.board[6][5] = Some(GamePiece::new(PieceColor::White));
+            engine.board[6][1] = Some(GamePiece::new(PieceColor::White));
+
+            let moves = engine.legal_moves();
+            assert!(!moves.is_empty());
+            for (index, m) in moves.iter().enumerate() {
+                assert!(!moves[index + 1..].contains(m), "duplicate {:?}", m);
+            }
+        }
+    }
+
+    #[test]
+    fn moves_by_origin_success() {
+        let engine = GameEngine::new();
+        let grouped = engine.moves_by_origin();
+
+        assert_eq!(grouped.len(), 4);
+        assert_eq!(grouped[&Coordinate(0, 5)], vec![Coordinate(1, 4)]);
+        assert_eq!(grouped[&Coordinate(2, 5)], vec![Coordinate(3, 4), Coordinate(1, 4)]);
+        assert_eq!(grouped[&Coordinate(4, 5)], vec![Coordinate(5, 4), Coordinate(3, 4)]);
+        assert_eq!(grouped[&Coordinate(6, 5)], vec![Coordinate(7, 4), Coordinate(5, 4)]);
+        assert!(!grouped.contains_key(&Coordinate(1, 6)));
+    }
+
+    #[test]
+    fn legal_moves_filtered_success() {
+        let mut engine = empty_engine(Rules::default());
+        engine.board[0][5] = Some(GamePiece::new(PieceColor::Black));
+        engine.board[5][2] = Some(GamePiece::crowned(GamePiece::new(PieceColor::Black)));
+
+        let men = engine.legal_moves_filtered(PieceKind::Man);
+        let kings = engine.legal_moves_filtered(PieceKind::King);
+        assert_eq!(men, vec![Move::new((0, 5), (1, 4))]);
+        assert_eq!(kings.len(), 4);
+        assert!(kings.iter().all(|m| m.from == Coordinate(5, 2)));
+        assert_eq!(men.len() + kings.len(), engine.legal_moves().len());
+    }
+
+    #[test]
+    fn legal_moves_for_piece_frozen() {
+        let mut engine = GameEngine::with_rules(Rules::american());
+        engine.board[1][4] = Some(GamePiece::new(PieceColor::White));
+
+        // (6, 5) has simple steps but a jump is forced elsewhere
+        assert_eq!(engine.valid_moves_from(Coordinate(6, 5)).len(), 2);
+        assert_eq!(engine.legal_moves_for_piece(Coordinate(6, 5)), vec![]);
+
+        assert_eq!(
+            engine.legal_moves_for_piece(Coordinate(0, 5)),
+            vec![Move::new((0, 5), (2, 3))]
+        );
+        assert_eq!(
+            engine.legal_moves_for_piece(Coordinate(2, 5)),
+            vec![Move::new((2, 5), (0, 3))]
+        );
+    }
+
+    #[test]
+    fn legal_moves_for_piece_optional_capture() {
+        let mut engine = GameEngine::new();
+        engine.board[1][4] = Some(GamePiece::new(PieceColor::White));
+
+        // capture isn't mandatory by default, so (6, 5) keeps its steps
+        let moves = engine.legal_moves_for_piece(Coordinate(6, 5));
+        assert_eq!(
+            moves,
+            vec![Move::new((6, 5), (7, 4)), Move::new((6, 5), (5, 4))]
+        );
+        assert!(engine.move_piece(&moves[0]).is_ok());
+    }
+
+    #[test]
+    fn legal_moves_for_piece_no_capture() {
+        let engine = GameEngine::new();
+        assert_eq!(
+            engine.legal_moves_for_piece(Coordinate(6, 5)),
+            vec![Move::new((6, 5), (7, 4)), Move::new((6, 5), (5, 4))]
+        );
+        // not the current player's piece
+        assert_eq!(engine.legal_moves_for_piece(Coordinate(1, 2)), vec![]);
+    }
+
+    #[test]
+    fn clickable_destinations_success() {
+        let mut engine = GameEngine::with_rules(Rules::american());
+        assert_eq!(
+            engine.clickable_destinations(Coordinate(6, 5)),
+            vec![Coordinate(7, 4), Coordinate(5, 4)]
+        );
+
+        engine.board[1][4] = Some(GamePiece::new(PieceColor::White));
+        assert_eq!(engine.clickable_destinations(Coordinate(6, 5)), vec![]);
+        assert_eq!(
+            engine.clickable_destinations(Coordinate(0, 5)),
+            vec![Coordinate(2, 3)]
+        );
+    }
+
+    #[test]
+    fn clickable_destinations_optional_capture() {
+        let mut engine = GameEngine::new();
+        engine.board[1][4] = Some(GamePiece::new(PieceColor::White));
+        assert_eq!(
+            engine.clickable_destinations(Coordinate(6, 5)),
+            vec![Coordinate(7, 4), Coordinate(5, 4)]
+        );
+        assert_eq!(
+            engine.clickable_destinations(Coordinate(0, 5)),
+            vec![Coordinate(2, 3)]
+        );
+    }
+
+    #[test]
+    fn is_piece_blocked_success() {
+        let mut engine = empty_engine(Rules::default());
+        let king = Coordinate(7, 0);
+        engine.board[7][0] = Some(GamePiece::crowned(GamePiece::new(PieceColor::Black)));
+        engine.board[6][1] = Some(GamePiece::new(PieceColor::Black));
+        assert!(engine.is_piece_blocked(king));
+        assert!(!engine.is_piece_blocked(Coordinate(6, 1)));
+
+        engine.board[6][1] = None;
+        assert!(!engine.is_piece_blocked(king));
+        assert!(!engine.is_piece_blocked(Coordinate(6, 1)));
+    }
+
+    #[test]
+    fn legal_moves_assuming_success() {
+        let engine = GameEngine::from_pieces(
+            &[(Coordinate(1, 0), GamePiece::new(PieceColor::White))],
+            PieceColor::White,
+        )
+        .unwrap();
+        let king = GamePiece::crowned(GamePiece::new(PieceColor::Black));
+        assert_eq!(
+            engine.legal_moves_assuming(Coordinate(3, 4), king),
+            vec![
+                Move::new((3, 4), (2, 5)),
+                Move::new((3, 4), (4, 5)),
+                Move::new((3, 4), (4, 3)),
+                Move::new((3, 4), (2, 3))
+            ]
+        );
+        assert_eq!(engine.piece_at(Coordinate(3, 4)), None);
+        assert_eq!(engine.current_turn(), PieceColor::White);
+
+        let opening = GameEngine::new();
+        assert_eq!(
+            opening.legal_moves_assuming(Coordinate(3, 4), king),
+            vec![Move::new((3, 4), (4, 3)), Move::new((3, 4), (2, 3))]
+        );
+        assert_eq!(opening.legal_moves_assuming(Coordinate(8, 4), king), vec![]);
+        assert_eq!(opening.legal_moves_assuming(Coordinate(3, 3), king), vec![]);
+    }
+
+    #[test]
+    fn outcome_in_progress() {
+        let engine = GameEngine::new();
+        assert_eq!(engine.outcome(), None);
+        assert_eq!(engine.winner(), None);
+    }
+
+    #[test]
+    fn outcome_blocked_loses() {
+        let mut engine = GameEngine::new();
+        engine.board = [[None; BOARD_SIZE]; BOARD_SIZE];
+        let king = GamePiece::crowned(GamePiece::new(PieceColor::White));
+        engine.board[0][1] = Some(GamePiece::new(PieceColor::Black));
+        engine.board[2][1] = Some(GamePiece::new(PieceColor::Black));
+        engine.board[1][0] = Some(king);
+        engine.board[3][0] = Some(king);
+
+        assert_eq!(engine.piece_count(PieceColor::Black), 2);
+        assert_eq!(engine.outcome(), Some(Outcome::Win(PieceColor::White)));
+        assert_eq!(engine.winner(), Some(PieceColor::White));
+    }
+
+    #[test]
+    fn move_after_game_over_failure() {
+        let mut engine = empty_engine(Rules::default());
+        engine.board[3][4] = Some(GamePiece::new(PieceColor::Black));
+        engine.board[2][3] = Some(GamePiece::new(PieceColor::White));
+
+        engine.move_piece(&Move::new((3, 4), (1, 2))).unwrap();
+        assert_eq!(engine.winner(), Some(PieceColor::Black));
+
+        let res = engine.move_piece(&Move::new((1, 2), (0, 1)));
+        assert_eq!(res, Err(MoveError::GameOver));
+        assert!(engine.board[1][2].is_some());
+        assert!(engine.board[0][1].is_none());
+    }
+
+    #[test]
+    fn move_after_draw_failure() {
+        let mut engine = GameEngine::new();
+        engine.plies_since_progress = DRAW_PLY_LIMIT - 1;
+        engine.move_piece(&Move::new((0, 5), (1, 4))).unwrap();
+
+        let res = engine.move_piece(&Move::new((1, 2), (0, 3)));
+        assert_eq!(res, Err(MoveError::GameOver));
+    }
+
+    #[test]
+    fn move_in_drawn_position_failure() {
+        let king = |color| GamePiece::crowned(GamePiece::new(color));
+        let pieces = [
+            (Coordinate(0, 7), king(PieceColor::Black)),
+            (Coordinate(7, 0), king(PieceColor::White)),
+        ];
+        let mut engine = GameEngine::from_pieces(&pieces, PieceColor::Black).unwrap();
+        assert_eq!(engine.status(), GameStatus::Draw);
+
+        let res = engine.move_piece(&Move::new((0, 7), (1, 6)));
+        assert_eq!(res, Err(MoveError::GameOver));
+        assert_eq!(
+            engine.piece_at(Coordinate(0, 7)),
+            Some(king(PieceColor::Black))
+        );
+    }
+
+    #[test]
+    fn material_balance_success() {
+        let mut engine = GameEngine::new();
+        assert_eq!(engine.material_balance(), 0);
+
+        engine.board[1][0] = None;
+        engine.board[3][0] = None;
+        engine.board[0][7] = Some(GamePiece::crowned(GamePiece::new(PieceColor::Black)));
+        assert_eq!(engine.material_balance(), 3);
+
+        engine.board = [[None; BOARD_SIZE]; BOARD_SIZE];
+        engine.board[0][1] = Some(GamePiece::crowned(GamePiece::new(PieceColor::White)));
+        engine.board[1][6] = Some(GamePiece::new(PieceColor::Black));
+        assert_eq!(engine.material_balance(), -1);
+    }
+
+    #[test]
+    fn board_rows_success() {
+        let mut engine = empty_engine(Rules::default());
+        let piece = GamePiece::new(PieceColor::Black);
+        engine.board[0][5] = Some(piece);
+
+        let rows = engine.board_rows();
+        assert_eq!(rows[5][0], Some(piece));
+        assert_eq!(rows[0][5], None);
+    }
+
+    #[test]
+    fn find_pieces_success() {
+        let mut engine = empty_engine(Rules::default());
+        let king = GamePiece::crowned(GamePiece::new(PieceColor::White));
+        engine.board[1][0] = Some(king);
+        engine.board[4][3] = Some(GamePiece::new(PieceColor::White));
+        engine.board[6][5] = Some(GamePiece::crowned(GamePiece::new(PieceColor::Black)));
+
+        let kings = engine.find_pieces(|piece| piece.crowned);
+        assert_eq!(kings, vec![Coordinate(1, 0), Coordinate(6, 5)]);
+
+        let white_kings = engine.find_pieces(|piece| *piece == king);
+        assert_eq!(white_kings, vec![Coordinate(1, 0)]);
+        assert!(engine.find_pieces(|_| false).is_empty());
+    }
+
+    #[test]
+    fn resign_success() {
+        let mut engine = GameEngine::new();
+        assert_eq!(engine.status(), GameStatus::InProgress);
+
+        engine.resign(PieceColor::White).unwrap();
+        assert_eq!(engine.status(), GameStatus::Resigned(PieceColor::White));
+        assert_eq!(engine.winner(), Some(PieceColor::Black));
+        assert_eq!(
+            engine.move_piece(&Move::new((0, 5), (1, 4))),
+            Err(MoveError::GameOver)
+        );
+        assert_eq!(engine.resign(PieceColor::Black), Err(MoveError::GameOver));
+    }
+
+    #[test]
+    fn draw_offer_success() {
+        let mut engine = GameEngine::new();
+        assert_eq!(
+            engine.accept_draw(PieceColor::White),
+            Err(MoveError::NoDrawOffer)
+        );
+
+        // an offer lapses once a move is made
+        engine.offer_draw(PieceColor::Black).unwrap();
+        engine.move_piece(&Move::new((0, 5), (1, 4))).unwrap();
+        assert_eq!(
+            engine.accept_draw(PieceColor::White),
+            Err(MoveError::NoDrawOffer)
+        );
+
+        engine.offer_draw(PieceColor::White).unwrap();
+        assert_eq!(
+            engine.accept_draw(PieceColor::White),
+            Err(MoveError::NoDrawOffer)
+        );
+        engine.accept_draw(PieceColor::Black).unwrap();
+        assert_eq!(engine.status(), GameStatus::Draw);
+        assert_eq!(engine.outcome(), Some(Outcome::Draw));
+        assert_eq!(
+            engine.move_piece(&Move::new((1, 2), (0, 3))),
+            Err(MoveError::GameOver)
+        );
+    }
+
+    #[test]
+    fn has_legal_move_success() {
+        let engine = GameEngine::new();
+        assert!(engine.has_legal_move());
+        assert!(!engine.is_game_over());
+
+        let mut engine = empty_engine(Rules::default());
+        let king = GamePiece::crowned(GamePiece::new(PieceColor::White));
+        engine.board[0][1] = Some(GamePiece::new(PieceColor::Black));
+        engine.board[2][1] = Some(GamePiece::new(PieceColor::Black));
+        engine.board[1][0] = Some(king);
+        engine.board[3][0] = Some(king);
+        assert!(!engine.has_legal_move());
+        assert_eq!(engine.legal_moves(), vec![]);
+        assert!(engine.is_game_over());
+    }
+
+    #[test]
+    fn outcome_draw_success() {
+        let mut engine = GameEngine::new();
+        engine.plies_since_progress = DRAW_PLY_LIMIT;
+        assert_eq!(engine.outcome(), Some(Outcome::Draw));
+        assert_eq!(engine.winner(), None);
+    }
+
+    #[test]
+    fn try_move_success() {
+        let mut engine = GameEngine::new();
+        assert!(engine.try_move((0, 5), (1, 4)));
+        assert_eq!(engine.current_turn(), PieceColor::White);
+        assert!(engine.board[1][4].is_some());
+    }
+
+    #[test]
+    fn try_move_failure() {
+        let mut engine = GameEngine::new();
+        assert!(!engine.try_move((0, 5), (0, 4)));
+        assert!(!engine.try_move((9, 5), (1, 4)));
+        assert_eq!(engine.current_turn(), PieceColor::Black);
+        assert!(engine.board[0][5].is_some());
+    }
+
+    #[test]
+    fn plies_until_draw_success() {
+        let mut engine = GameEngine::new();
+        assert_eq!(engine.plies_until_draw(), Some(DRAW_PLY_LIMIT));
+
+        engine.move_piece(&Move::new((0, 5), (1, 4))).unwrap();
+        assert_eq!(engine.plies_until_draw(), Some(DRAW_PLY_LIMIT - 1));
+        engine.move_piece(&Move::new((1, 2), (0, 3))).unwrap();
+        assert_eq!(engine.plies_until_draw(), Some(DRAW_PLY_LIMIT - 2));
+
+        engine.rules = RulesBuilder::new().draw_ply_limit(None).build();
+        engine.plies_since_progress = DRAW_PLY_LIMIT;
+        assert_eq!(engine.plies_until_draw(), None);
+        assert!(!engine.is_drawn());
+    }
+
+    #[test]
+    fn mandatory_capture_rule() {
+        let mut engine = GameEngine::new();
+        engine.board[1][4] = Some(GamePiece::new(PieceColor::White));
+        assert_eq!(engine.legal_moves().len(), 7);
+
+        let rules = RulesBuilder::new().mandatory_capture(true).build();
+        let mut engine = GameEngine::with_rules(rules);
+        engine.board[1][4] = Some(GamePiece::new(PieceColor::White));
+        assert_eq!(
+            engine.legal_moves(),
+            vec![Move::new((0, 5), (2, 3)), Move::new((2, 5), (0, 3))]
+        );
+    }
+
+    #[test]
+    fn men_capture_backward_rule() {
+        let mut engine = empty_engine(Rules::default());
+        engine.board[3][4] = Some(GamePiece::new(PieceColor::Black));
+        engine.board[2][5] = Some(GamePiece::new(PieceColor::White));
+        assert!(engine.legal_moves().contains(&Move::new((3, 4), (1, 6))));
+
+        engine.rules = RulesBuilder::new().men_capture_backward(false).build();
+        assert!(!engine.legal_moves().contains(&Move::new((3, 4), (1, 6))));
+    }
+
+    #[test]
+    fn flying_kings_rule() {
+        let mut engine = empty_engine(Rules::default());
+        engine.board[0][7] = Some(GamePiece::crowned(GamePiece::new(PieceColor::Black)));
+        engine.board[5][2] = Some(GamePiece::new(PieceColor::White));
+        assert_eq!(engine.legal_moves(), vec![Move::new((0, 7), (1, 6))]);
+
+        engine.rules = RulesBuilder::new().flying_kings(true).build();
+        assert_eq!(
+            engine.legal_moves(),
+            vec![
+                Move::new((0, 7), (6, 1)),
+                Move::new((0, 7), (7, 0)),
+                Move::new((0, 7), (1, 6)),
+                Move::new((0, 7), (2, 5)),
+                Move::new((0, 7), (3, 4)),
+                Move::new((0, 7), (4, 3)),
+            ]
+        );
+
+        engine.move_piece(&Move::new((0, 7), (7, 0))).unwrap();
+        assert_eq!(engine.piece_count(PieceColor::White), 0);
+    }
+
+    #[test]
+    fn longest_capture_rule() {
+        let mandatory = RulesBuilder::new().mandatory_capture(true);
+        let mut engine = empty_engine(mandatory.build());
+        engine.board[0][5] = Some(GamePiece::new(PieceColor::Black));
+        engine.board[7][6] = Some(GamePiece::new(PieceColor::Black));
+        engine.board[1][4] = Some(GamePiece::new(PieceColor::White));
+        engine.board[6][5] = Some(GamePiece::new(PieceColor::White));
+        engine.board[4][3] = Some(GamePiece::new(PieceColor::White));
+        assert_eq!(
+            engine.legal_moves(),
+            vec![Move::new((0, 5), (2, 3)), Move::new((7, 6), (5, 4))]
+        );
+
+        engine.rules = mandatory.longest_capture(true).build();
+        assert_eq!(engine.legal_moves(), vec![Move::new((7, 6), (5, 4))]);
+    }
+
+    #[test]
+    fn orthogonal_targets_success() {
+        let loc = Coordinate(3, 4);
+        assert_eq!(
+            loc.orthogonal_move_targets_from()
+                .collect::<Vec<Coordinate>>(),
+            vec![
+                Coordinate(3, 3),
+                Coordinate(4, 4),
+                Coordinate(3, 5),
+                Coordinate(2, 4)
+            ]
+        );
+        assert_eq!(
+            loc.orthogonal_jump_targets_from()
+                .collect::<Vec<Coordinate>>(),
+            vec![
+                Coordinate(3, 2),
+                Coordinate(5, 4),
+                Coordinate(3, 6),
+                Coordinate(1, 4)
+            ]
+        );
+    }
+
+    #[test]
+    fn orthogonal_moves_rule() {
+        let rules = RulesBuilder::new().orthogonal_moves(true).build();
+        let mut engine = empty_engine(rules);
+        engine.board[3][4] = Some(GamePiece::new(PieceColor::Black));
+        assert_eq!(
+            engine.legal_moves(),
+            vec![
+                Move::new((3, 4), (3, 3)),
+                Move::new((3, 4), (4, 4)),
+                Move::new((3, 4), (2, 4))
+            ]
+        );
+
+        engine.board[3][3] = Some(GamePiece::new(PieceColor::White));
+        engine.board[4][4] = Some(GamePiece::new(PieceColor::White));
+        assert_eq!(
+            engine.capture_moves(),
+            vec![Move::new((3, 4), (3, 2)), Move::new((3, 4), (5, 4))]
+        );
+        let result = engine.move_piece(&Move::new((3, 4), (5, 4))).unwrap();
+        assert_eq!(
+            result.captured_pieces,
+            vec![(Coordinate(4, 4), GamePiece::new(PieceColor::White))]
+        );
+    }
+
+    #[test]
+    fn move_piece_sideways_success() {
+        let rules = RulesBuilder::new().orthogonal_moves(true).build();
+        let mut engine = GameEngine::with_rules(rules);
+        engine.initialize_turkish_pieces();
+        engine.move_piece(&Move::new((0, 5), (0, 4))).unwrap();
+        engine.move_piece(&Move::new((0, 2), (0, 3))).unwrap();
+
+        let res = engine.move_piece(&Move::new((1, 5), (0, 5))).unwrap();
+        assert_eq!(res.current_turn, PieceColor::White);
+        assert_eq!(engine.board[1][5], None);
+        assert_eq!(engine.board[0][5], Some(GamePiece::new(PieceColor::Black)));
+    }
+
+    #[test]
+    fn initialize_turkish_pieces_success() {
+        let rules = RulesBuilder::new().orthogonal_moves(true).build();
+        let mut engine = GameEngine::with_rules(rules);
+        engine.initialize_turkish_pieces();
+        assert_eq!(engine.piece_count(PieceColor::Black), 16);
+        assert_eq!(engine.piece_count(PieceColor::White), 16);
+        assert_eq!(engine.legal_moves().len(), 8);
+        assert!(engine.has_legal_move());
+        assert_eq!(engine.check_invariants(), Ok(()));
+    }
+
+    #[test]
+    fn max_capture_length_success() {
+        let mut engine = empty_engine(Rules::default());
+        engine.board[0][5] = Some(GamePiece::new(PieceColor::Black));
+        engine.board[7][6] = Some(GamePiece::new(PieceColor::Black));
+        engine.board[1][4] = Some(GamePiece::new(PieceColor::White));
+        engine.board[6][5] = Some(GamePiece::new(PieceColor::White));
+        engine.board[4][3] = Some(GamePiece::new(PieceColor::White));
+        engine.board[2][1] = Some(GamePiece::new(PieceColor::White));
+        assert_eq!(engine.max_capture_length(), 3);
+
+        engine.board[7][6] = None;
+        assert_eq!(engine.max_capture_length(), 1);
+
+        assert_eq!(GameEngine::new().max_capture_length(), 0);
+    }
+
+    #[test]
+    fn deferred_capture_removal_rule() {
+        let setup = |deferred: bool| {
+            let rules = RulesBuilder::from_rules(Rules::international())
+                .longest_capture(false)
+                .deferred_capture_removal(deferred)
+                .build();
+            let mut engine = empty_engine(rules);
+            engine.board[5][6] = Some(GamePiece::crowned(GamePiece::new(PieceColor::Black)));
+            engine.board[3][4] = Some(GamePiece::new(PieceColor::White));
+            engine.board[2][1] = Some(GamePiece::new(PieceColor::White));
+            engine.board[4][1] = Some(GamePiece::new(PieceColor::White));
+            engine.board[4][3] = Some(GamePiece::new(PieceColor::White));
+            engine.move_piece(&Move::new((5, 6), (1, 2))).unwrap();
+            engine.move_piece(&Move::new((1, 2), (3, 0))).unwrap();
+            engine
+        };
+
+        // with immediate removal the king can cross the square of the first capture
+        let mut engine = setup(false);
+        engine.move_piece(&Move::new((3, 0), (5, 2))).unwrap();
+        assert_eq!(engine.current_turn(), PieceColor::Black);
+        assert!(engine.legal_moves().contains(&Move::new((5, 2), (3, 4))));
+
+        // with deferred removal the captured piece still blocks the path, so the turn ends
+        let mut engine = setup(true);
+        assert!(engine.board[3][4].is_some());
+        engine.move_piece(&Move::new((3, 0), (5, 2))).unwrap();
+        assert_eq!(engine.current_turn(), PieceColor::White);
+        assert!(engine.board[3][4].is_none());
+        assert!(engine.board[2][1].is_none());
+        assert!(engine.board[4][1].is_none());
+        assert!(engine.board[4][3].is_some());
+    }
+
+    #[test]
+    fn multi_jump_crowns_at_end() {
+        let mut engine = empty_engine(Rules::american());
+        engine.board[5][4] = Some(GamePiece::new(PieceColor::Black));
+        engine.board[4][3] = Some(GamePiece::new(PieceColor::White));
+        engine.board[2][1] = Some(GamePiece::new(PieceColor::White));
+
+        let results = engine
+            .move_path(&[Coordinate(5, 4), Coordinate(3, 2), Coordinate(1, 0)])
+            .unwrap();
+        assert!(!results[0].crowned);
+        assert!(results[1].crowned);
+        assert!(engine.is_crowned(Coordinate(1, 0)));
+    }
+
+    #[test]
+    fn multi_jump_through_crowning_row() {
+        let setup = |crowning_ends_capture: bool| {
+            let rules = RulesBuilder::new()
+                .crowning_ends_capture(crowning_ends_capture)
+                .build();
+            let mut engine = empty_engine(rules);
+            engine.board[5][2] = Some(GamePiece::new(PieceColor::Black));
+            engine.board[4][1] = Some(GamePiece::new(PieceColor::White));
+            engine.board[2][1] = Some(GamePiece::new(PieceColor::White));
+            engine
+        };
+
+        // the man passes through the crowning row and ends the capture as a man
+        let mut engine = setup(false);
+        let results = engine
+            .move_path(&[Coordinate(5, 2), Coordinate(3, 0), Coordinate(1, 2)])
+            .unwrap();
+        assert!(results.iter().all(|result| !result.crowned));
+        assert!(!engine.is_crowned(Coordinate(1, 2)));
+
+        // reaching the crowning row ends the capture there
+        let mut engine = setup(true);
+        let result = engine.move_piece(&Move::new((5, 2), (3, 0))).unwrap();
+        assert!(result.crowned);
+        assert_eq!(engine.current_turn(), PieceColor::White);
+    }
+
+    #[test]
+    fn multi_jump_continuation() {
+        let mut engine = empty_engine(Rules::american());
+        engine.board[0][5] = Some(GamePiece::new(PieceColor::Black));
+        engine.board[7][6] = Some(GamePiece::new(PieceColor::Black));
+        engine.board[6][5] = Some(GamePiece::new(PieceColor::White));
+        engine.board[4][3] = Some(GamePiece::new(PieceColor::White));
+
+        let result = engine.move_piece(&Move::new((7, 6), (5, 4))).unwrap();
+        assert_eq!(result.current_turn, PieceColor::Black);
+        assert_eq!(engine.current_turn(), PieceColor::Black);
+        assert_eq!(engine.legal_moves(), vec![Move::new((5, 4), (3, 2))]);
+        assert!(engine.move_piece(&Move::new((0, 5), (1, 4))).is_err());
+
+        let result = engine.move_piece(&Move::new((5, 4), (3, 2))).unwrap();
+        assert_eq!(result.current_turn, PieceColor::White);
+        assert_eq!(engine.current_turn(), PieceColor::White);
+        assert_eq!(engine.piece_count(PieceColor::White), 0);
+    }
+
+    #[test]
+    fn multi_jump_rule() {
+        let rules = RulesBuilder::from_rules(Rules::american())
+            .multi_jump(false)
+            .build();
+        let mut engine = empty_engine(rules);
+        engine.board[0][5] = Some(GamePiece::new(PieceColor::Black));
+        engine.board[7][6] = Some(GamePiece::new(PieceColor::Black));
+        engine.board[6][5] = Some(GamePiece::new(PieceColor::White));
+        engine.board[4][3] = Some(GamePiece::new(PieceColor::White));
+        assert_eq!(engine.max_capture_length(), 1);
+
+        let result = engine.move_piece(&Move::new((7, 6), (5, 4))).unwrap();
+        assert_eq!(result.current_turn, PieceColor::White);
+        assert_eq!(engine.jumping_piece(), None);
+        assert_eq!(engine.piece_count(PieceColor::White), 1);
+    }
+
+    #[test]
+    fn move_path_success() {
+        let mut engine = empty_engine(Rules::american());
+        engine.board[7][6] = Some(GamePiece::new(PieceColor::Black));
+        engine.board[6][5] = Some(GamePiece::new(PieceColor::White));
+        engine.board[4][3] = Some(GamePiece::new(PieceColor::White));
+
+        let path = [Coordinate(7, 6), Coordinate(5, 4), Coordinate(3, 2)];
+        let results = engine.move_path(&path).unwrap();
+        assert_eq!(results.len(), 2);
+        assert_eq!(engine.piece_count(PieceColor::White), 0);
+        assert_eq!(engine.current_turn(), PieceColor::White);
+    }
+
+    #[test]
+    fn move_path_invalid_hop_failure() {
+        let mut engine = empty_engine(Rules::american());
+        engine.board[7][6] = Some(GamePiece::new(PieceColor::Black));
+        engine.board[6][5] = Some(GamePiece::new(PieceColor::White));
+        engine.board[2][1] = Some(GamePiece::new(PieceColor::White));
+        engine.board[4][3] = Some(GamePiece::new(PieceColor::Black));
+        let before = engine.to_bytes();
+
+        // second hop jumps a friendly piece
+        let path = [Coordinate(7, 6), Coordinate(5, 4), Coordinate(3, 2)];
+        assert_eq!(engine.move_path(&path), Err(MoveError::IllegalMove));
+        assert_eq!(engine.to_bytes(), before);
+
+        // second hop jumps an empty square
+        engine.board[4][3] = None;
+        let before = engine.to_bytes();
+        let path = [Coordinate(7, 6), Coordinate(5, 4), Coordinate(3, 2), Coordinate(1, 0)];
+        assert_eq!(engine.move_path(&path), Err(MoveError::IllegalMove));
+        assert_eq!(engine.to_bytes(), before);
+    }
+
+    #[test]
+    fn move_path_incomplete_failure() {
+        let mut engine = empty_engine(Rules::american());
+        engine.board[7][6] = Some(GamePiece::new(PieceColor::Black));
+        engine.board[6][5] = Some(GamePiece::new(PieceColor::White));
+        engine.board[4][3] = Some(GamePiece::new(PieceColor::White));
+        let before = engine.to_bytes();
+
+        let path = [Coordinate(7, 6), Coordinate(5, 4)];
+        assert_eq!(engine.move_path(&path), Err(MoveError::IllegalMove));
+        assert_eq!(engine.to_bytes(), before);
+        assert_eq!(engine.move_path(&[Coordinate(7, 6)]), Err(MoveError::IllegalMove));
+    }
+
+    #[test]
+    fn captured_pieces_success() {
+        let mut engine = empty_engine(Rules::default());
+        let king = GamePiece::crowned(GamePiece::new(PieceColor::White));
+        engine.board[3][4] = Some(GamePiece::new(PieceColor::Black));
+        engine.board[2][3] = Some(king);
+
+        let result = engine.move_piece(&Move::new((3, 4), (1, 2))).unwrap();
+        assert_eq!(result.captured_pieces, vec![(Coordinate(2, 3), king)]);
+        assert_eq!(result.captured_pieces[0].1.color, PieceColor::White);
+    }
+
+    #[test]
+    fn captured_tally_success() {
+        let mut engine = empty_engine(Rules::american());
+        engine.board[7][6] = Some(GamePiece::new(PieceColor::Black));
+        engine.board[6][5] = Some(GamePiece::new(PieceColor::White));
+        engine.board[4][3] = Some(GamePiece::new(PieceColor::White));
+        engine.board[0][1] = Some(GamePiece::new(PieceColor::White));
+
+        engine.move_piece(&Move::new((7, 6), (5, 4))).unwrap();
+        assert_eq!(engine.white_captured(), 1);
+        engine.move_piece(&Move::new((5, 4), (3, 2))).unwrap();
+        assert_eq!(engine.white_captured(), 2);
+        assert_eq!(engine.black_captured(), 0);
+    }
+
+    #[test]
+    fn swap_side_to_move_success() {
+        let mut engine = GameEngine::new();
+        engine.move_piece(&Move::new((2, 5), (3, 4))).unwrap();
+        assert_eq!(engine.move_count(), 1);
+
+        engine.swap_side_to_move();
+        assert_eq!(engine.current_turn(), PieceColor::Black);
+        assert_eq!(engine.move_count(), 1);
+    }
+
+    #[test]
+    fn swap_all_colors_success() {
+        let mut engine = GameEngine::new();
+        engine.move_piece(&Move::new((2, 5), (3, 4))).unwrap();
+        engine.move_piece(&Move::new((1, 2), (2, 3))).unwrap();
+        engine.move_piece(&Move::new((3, 4), (1, 2))).unwrap();
+        let black = engine.piece_count(PieceColor::Black);
+        let white = engine.piece_count(PieceColor::White);
+        assert_ne!(black, white);
+
+        engine.swap_all_colors();
+        assert_eq!(engine.piece_count(PieceColor::Black), white);
+        assert_eq!(engine.piece_count(PieceColor::White), black);
+        assert_eq!(
+            engine.piece_at(Coordinate(1, 2)),
+            Some(GamePiece::new(PieceColor::White))
+        );
+    }
+
+    #[test]
+    fn mirror_success() {
+        let mut engine = GameEngine::new();
+        engine.move_piece(&Move::new((2, 5), (3, 4))).unwrap();
+        engine.move_piece(&Move::new((1, 2), (2, 3))).unwrap();
+
+        let mirrored = engine.mirror();
+        assert_eq!(mirrored.current_turn(), PieceColor::White);
+        assert_eq!(
+            mirrored.piece_at(Coordinate(4, 3)),
+            Some(GamePiece::new(PieceColor::White))
+        );
+
+        let flip = |Coordinate(x, y)| Coordinate(7 - x, 7 - y);
+        let moves = engine.legal_moves();
+        let mirrored_moves = mirrored.legal_moves();
+        assert_eq!(moves.len(), mirrored_moves.len());
+        for m in moves {
+            assert!(mirrored_moves.contains(&Move {
+                from: flip(m.from),
+                to: flip(m.to),
+            }));
+        }
+    }
+
+    #[test]
+    fn has_capture_success() {
+        let mut engine = GameEngine::new();
+        assert!(!engine.has_capture());
+
+        engine.board[1][4] = Some(GamePiece::new(PieceColor::White));
+        assert!(engine.has_capture());
+    }
+
+    #[test]
+    fn pieces_in_danger_success() {
+        let mut engine = GameEngine::new();
+        assert_eq!(engine.pieces_in_danger(), vec![]);
+
+        engine.move_piece(&Move::new((2, 5), (3, 4))).unwrap();
+        engine.move_piece(&Move::new((5, 2), (4, 3))).unwrap();
+        assert_eq!(engine.pieces_in_danger(), vec![Coordinate(3, 4)]);
+    }
+
+    #[test]
+    fn from_pieces_success() {
+        let engine = GameEngine::from_pieces(
+            &[
+                (Coordinate(1, 0), GamePiece::new(PieceColor::Black)),
+                (
+                    Coordinate(3, 4),
+                    GamePiece::crowned(GamePiece::new(PieceColor::White)),
+                ),
+            ],
+            PieceColor::White,
+        )
+        .unwrap();
+        assert_eq!(engine.current_turn(), PieceColor::White);
+        assert_eq!(engine.piece_count(PieceColor::Black), 1);
+        assert_eq!(engine.king_count(PieceColor::White), 1);
+        assert_eq!(
+            engine.legal_moves(),
+            vec![
+                Move::new((3, 4), (2, 5)),
+                Move::new((3, 4), (4, 5)),
+                Move::new((3, 4), (4, 3)),
+                Move::new((3, 4), (2, 3))
+            ]
+        );
+    }
+
+    #[test]
+    fn from_pieces_failure() {
+        let black = GamePiece::new(PieceColor::Black);
+        assert_eq!(
+            GameEngine::from_pieces(&[(Coordinate(0, 0), black)], PieceColor::Black).err(),
+            Some(BoardError::LightSquare)
+        );
+        assert_eq!(
+            GameEngine::from_pieces(&[(Coordinate(8, 1), black)], PieceColor::Black).err(),
+            Some(BoardError::OffBoard)
+        );
+    }
+
+    #[test]
+    fn set_crowned_success() {
+        let mut engine = GameEngine::new();
+        let coord = Coordinate(0, 5);
+        assert!(!engine.is_crowned(coord));
+
+        assert_eq!(engine.set_crowned(coord, true), Ok(()));
+        assert!(engine.is_crowned(coord));
+
+        assert_eq!(engine.set_crowned(coord, false), Ok(()));
+        assert!(!engine.is_crowned(coord));
+    }
+
+    #[test]
+    fn set_crowned_failure() {
+        let mut engine = GameEngine::new();
+        assert_eq!(
+            engine.set_crowned(Coordinate(1, 4), true),
+            Err(BoardError::EmptySquare)
+        );
+        assert_eq!(
+            engine.set_crowned(Coordinate(8, 1), true),
+            Err(BoardError::OffBoard)
+        );
+    }
+
+    #[test]
+    fn en_prise_value_success() {
+        let mut engine = empty_engine(Rules::default());
+        engine.board[3][4] = Some(GamePiece::crowned(GamePiece::new(PieceColor::Black)));
+        engine.board[0][7] = Some(GamePiece::new(PieceColor::Black));
+        engine.board[4][3] = Some(GamePiece::new(PieceColor::White));
+
+        assert_eq!(engine.en_prise_value(PieceColor::Black), 2);
+        assert_eq!(engine.en_prise_value(PieceColor::White), 1);
+    }
+
+    #[test]
+    fn describe_move_success() {
+        let mut engine = GameEngine::new();
+        assert_eq!(engine.describe_move(&Move::new((0, 5), (1, 4))), "a3-b4");
+
+        engine.move_piece(&Move::new((2, 5), (3, 4))).unwrap();
+        engine.move_piece(&Move::new((1, 2), (2, 3))).unwrap();
+        assert_eq!(
+            engine.describe_move(&Move::new((3, 4), (1, 2))),
+            "d4xb6 (captures c5)"
+        );
+        assert_eq!(engine.describe_move(&Move::new((0, 5), (9, 4))), "a3-??");
+        assert_eq!(engine.describe_move(&Move::new((8, 8), (1, 4))), "??-b4");
+    }
+
+    #[test]
+    fn threats_after_success() {
+        let mut engine = empty_engine(Rules::default());
+        engine.board[2][5] = Some(GamePiece::new(PieceColor::Black));
+        engine.board[4][3] = Some(GamePiece::new(PieceColor::White));
+
+        assert_eq!(
+            engine.threats_after(&Move::new((2, 5), (3, 4))),
+            vec![Coordinate(3, 4)]
+        );
+        assert_eq!(engine.threats_after(&Move::new((2, 5), (1, 4))), vec![]);
+        assert_eq!(engine.threats_after(&Move::new((2, 5), (2, 4))), vec![]);
+    }
+
+    #[test]
+    fn is_move_safe_success() {
+        let engine = GameEngine::new();
+        assert!(engine.is_move_safe(&Move::new((0, 5), (1, 4))));
+        assert!(!engine.is_move_safe(&Move::new((0, 5), (0, 4))));
+
+        let mut engine = empty_engine(Rules::default());
+        engine.board[3][4] = Some(GamePiece::new(PieceColor::Black));
+        engine.board[2][3] = Some(GamePiece::new(PieceColor::White));
+        engine.board[7][0] = Some(GamePiece::new(PieceColor::White));
+        assert!(engine.is_move_safe(&Move::new((3, 4), (1, 2))));
+
+        // the capture walks into a recapture from (0, 1)
+        engine.board[0][1] = Some(GamePiece::new(PieceColor::White));
+        assert!(!engine.is_move_safe(&Move::new((3, 4), (1, 2))));
+    }
+
+    #[test]
+    fn capture_and_quiet_moves_success() {
+        let mut engine = GameEngine::new();
+        assert_eq!(engine.capture_moves(), vec![]);
+        assert_eq!(engine.quiet_moves().len(), 7);
+
+        engine.board[1][4] = Some(GamePiece::new(PieceColor::White));
+        let captures = vec![Move::new((0, 5), (2, 3)), Move::new((2, 5), (0, 3))];
+        assert_eq!(engine.capture_moves(), captures);
+        assert_eq!(engine.quiet_moves().len(), 5);
+
+        engine.rules = Rules::american();
+        assert_eq!(engine.capture_moves(), captures);
+        assert_eq!(engine.quiet_moves(), vec![]);
+    }
+
+    #[derive(Default)]
+    struct RecordingObserver {
+        moves: usize,
+        turn_changes: Vec<PieceColor>,
+        captures: Vec<(Coordinate, GamePiece)>,
+        crowns: Vec<Coordinate>,
+    }
+
+    impl GameObserver for RecordingObserver {
+        fn piece_moved(&mut self, _move_made: &Move) {
+            self.moves += 1;
+        }
+
+        fn piece_captured(&mut self, coord: Coordinate, piece: GamePiece) {
+            self.captures.push((coord, piece));
+        }
+
+        fn piece_crowned(&mut self, coord: Coordinate) {
+            self.crowns.push(coord);
+        }
+
+        fn turn_changed(&mut self, new_turn: PieceColor) {
+            self.turn_changes.push(new_turn);
+        }
+    }
+
+    #[test]
+    fn observer_turn_changed_once_per_move() {
+        let mut engine = empty_engine(Rules::american());
+        engine.board[7][6] = Some(GamePiece::new(PieceColor::Black));
+        engine.board[6][5] = Some(GamePiece::new(PieceColor::White));
+        engine.board[4][3] = Some(GamePiece::new(PieceColor::White));
+        engine.board[7][0] = Some(GamePiece::new(PieceColor::White));
+
+        let mut observer = RecordingObserver::default();
+        engine
+            .move_piece_observed(&Move::new((7, 6), (5, 4)), &mut observer)
+            .unwrap();
+        assert_eq!(observer.turn_changes, vec![]);
+        engine
+            .move_piece_observed(&Move::new((5, 4), (3, 2)), &mut observer)
+            .unwrap();
+        assert_eq!(observer.turn_changes, vec![PieceColor::White]);
+
+        engine
+            .move_piece_observed(&Move::new((7, 0), (6, 1)), &mut observer)
+            .unwrap();
+        assert_eq!(observer.moves, 3);
+        assert_eq!(
+            observer.turn_changes,
+            vec![PieceColor::White, PieceColor::Black]
+        );
+
+        // a rejected move reports nothing
+        assert!(engine
+            .move_piece_observed(&Move::new((3, 2), (3, 1)), &mut observer)
+            .is_err());
+        assert_eq!(observer.moves, 3);
+    }
+
+    #[test]
+    fn replay_with_success() {
+        let moves = [
+            Move::new((2, 5), (3, 4)),
+            Move::new((1, 2), (2, 3)),
+            Move::new((3, 4), (1, 2)),
+        ];
+        let mut observer = RecordingObserver::default();
+        let engine = GameEngine::replay_with(&moves, &mut observer).unwrap();
+        assert_eq!(engine.white_captured(), 1);
+        assert_eq!(observer.moves, 3);
+        assert_eq!(
+            observer.turn_changes,
+            vec![PieceColor::White, PieceColor::Black, PieceColor::White]
+        );
+        assert_eq!(
+            observer.captures,
+            vec![(Coordinate(2, 3), GamePiece::new(PieceColor::White))]
+        );
+        assert_eq!(observer.crowns, vec![]);
+    }
+
+    #[test]
+    fn replay_with_failure() {
+        let moves = [Move::new((2, 5), (3, 4)), Move::new((3, 4), (4, 3))];
+        let mut observer = RecordingObserver::default();
+        let res = GameEngine::replay_with(&moves, &mut observer);
+        assert_eq!(res.err(), Some(MoveError::IllegalMove));
+        assert_eq!(observer.moves, 1);
+        assert_eq!(observer.turn_changes, vec![PieceColor::White]);
+    }
+
+    #[test]
+    fn pieces_of_success() {
+        let engine = GameEngine::new();
+        let black = engine.pieces_of(PieceColor::Black).collect::<Vec<_>>();
+        assert_eq!(black.len(), 12);
+        assert!(black
+            .iter()
+            .all(|&(coord, piece)| piece == GamePiece::new(PieceColor::Black)
+                && engine.piece_at(coord) == Some(piece)));
+        assert_eq!(
+            black[0],
+            (Coordinate(0, 5), GamePiece::new(PieceColor::Black))
+        );
+        assert_eq!(engine.pieces_of(PieceColor::White).count(), 12);
+    }
+
+    #[test]
+    fn edge_piece_count_success() {
+        let mut engine = GameEngine::new();
+        assert_eq!(engine.edge_piece_count(PieceColor::Black), 3);
+        assert_eq!(engine.edge_piece_count(PieceColor::White), 3);
+
+        engine.move_piece(&Move::new((0, 5), (1, 4))).unwrap();
+        assert_eq!(engine.edge_piece_count(PieceColor::Black), 2);
+    }
+
+    #[test]
+    fn back_row_intact_success() {
+        let mut engine = GameEngine::new();
+        assert!(engine.back_row_intact(PieceColor::Black));
+        assert!(engine.back_row_intact(PieceColor::White));
+
+        engine.board[3][0] = None;
+        assert!(engine.back_row_intact(PieceColor::Black));
+        assert!(!engine.back_row_intact(PieceColor::White));
+    }
+
+    #[test]
+    fn advancement_score_success() {
+        let mut engine = GameEngine::new();
+        assert_eq!(engine.advancement_score(PieceColor::Black), 12);
+        assert_eq!(engine.advancement_score(PieceColor::White), 12);
+
+        engine.move_piece(&Move::new((2, 5), (3, 4))).unwrap();
+        assert_eq!(engine.advancement_score(PieceColor::Black), 13);
+
+        engine.set_crowned(Coordinate(1, 0), true).unwrap();
+        assert_eq!(
+            engine.advancement_score(PieceColor::White),
+            12 + KING_ADVANCEMENT
+        );
+    }
+
+    #[test]
+    fn king_safety_success() {
+        let king = GamePiece::crowned(GamePiece::new(PieceColor::Black));
+        let mut cornered = empty_engine(Rules::default());
+        cornered.board[7][0] = Some(king);
+        cornered.board[0][1] = Some(GamePiece::new(PieceColor::White));
+        let mut centered = empty_engine(Rules::default());
+        centered.board[3][4] = Some(king);
+        centered.board[0][1] = Some(GamePiece::new(PieceColor::White));
+
+        assert_eq!(cornered.king_safety(PieceColor::Black), 6);
+        assert_eq!(centered.king_safety(PieceColor::Black), 0);
+        assert_eq!(cornered.king_safety(PieceColor::White), 0);
+
+        centered.board[4][3] = Some(GamePiece::new(PieceColor::White));
+        assert_eq!(
+            centered.king_safety(PieceColor::Black),
+            -KING_THREAT_PENALTY
+        );
+    }
+
+    #[test]
+    fn mobility_success() {
+        let engine = GameEngine::new();
+        assert_eq!(engine.mobility(PieceColor::Black), 7);
+        assert_eq!(engine.mobility(PieceColor::White), 7);
+        assert_eq!(engine.current_turn(), PieceColor::Black);
+    }
+
+    #[test]
+    fn insufficient_material_success() {
+        let mut engine = empty_engine(Rules::default());
+        engine.board[0][7] = Some(GamePiece::crowned(GamePiece::new(PieceColor::Black)));
+        engine.board[7][0] = Some(GamePiece::crowned(GamePiece::new(PieceColor::White)));
+        assert!(engine.is_insufficient_material());
+        assert_eq!(engine.outcome(), Some(Outcome::Draw));
+
+        // a man can still be crowned, so the game goes on
+        engine.board[7][0] = Some(GamePiece::new(PieceColor::White));
+        assert!(!engine.is_insufficient_material());
+        assert_eq!(engine.outcome(), None);
+    }
+
+    #[test]
+    fn board_checksum_success() {
+        let moves = [Move::new((2, 5), (3, 4)), Move::new((1, 2), (2, 3))];
+        let mut first = GameEngine::new();
+        let mut second = GameEngine::new();
+        first.apply_moves(&moves).unwrap();
+        second.apply_moves(&moves).unwrap();
+        assert_eq!(first.board_checksum(), second.board_checksum());
+        assert_eq!(
+            first.board_checksum(),
+            GameEngine::from_bytes(&first.to_bytes())
+                .unwrap()
+                .board_checksum()
+        );
+
+        second.swap_side_to_move();
+        assert_ne!(first.board_checksum(), second.board_checksum());
+        assert_ne!(first.board_checksum(), GameEngine::new().board_checksum());
+    }
+
+    #[test]
+    fn apply_partial_jump_success() {
+        let mut engine = empty_engine(Rules::default());
+        engine.board[3][6] = Some(GamePiece::new(PieceColor::Black));
+        engine.board[4][5] = Some(GamePiece::new(PieceColor::White));
+        engine.board[4][3] = Some(GamePiece::new(PieceColor::White));
+        engine.board[6][3] = Some(GamePiece::new(PieceColor::White));
+
+        let state = engine
+            .apply_partial_jump(Move::new((3, 6), (5, 4)))
+            .unwrap();
+        assert_eq!(
+            state,
+            JumpState {
+                complete: false,
+                continuations: vec![Coordinate(7, 2), Coordinate(3, 2)],
+            }
+        );
+        assert_eq!(engine.current_turn(), PieceColor::Black);
+
+        let state = engine
+            .apply_partial_jump(Move::new((5, 4), (3, 2)))
+            .unwrap();
+        assert_eq!(
+            state,
+            JumpState {
+                complete: true,
+                continuations: vec![],
+            }
+        );
+        assert_eq!(engine.current_turn(), PieceColor::White);
+    }
+
+    #[test]
+    fn is_legal_prefix_success() {
+        let mut engine = empty_engine(Rules::default());
+        engine.board[3][6] = Some(GamePiece::new(PieceColor::Black));
+        engine.board[4][5] = Some(GamePiece::new(PieceColor::White));
+        engine.board[4][3] = Some(GamePiece::new(PieceColor::White));
+
+        assert!(engine.is_legal_prefix(&[Coordinate(3, 6)]));
+        assert!(engine.is_legal_prefix(&[Coordinate(3, 6), Coordinate(5, 4)]));
+        assert!(engine.is_legal_prefix(&[Coordinate(3, 6), Coordinate(5, 4), Coordinate(3, 2)]));
+        assert_eq!(engine.move_count(), 0);
+    }
+
+    #[test]
+    fn is_legal_prefix_failure() {
+        let mut engine = empty_engine(Rules::default());
+        engine.board[3][6] = Some(GamePiece::new(PieceColor::Black));
+        engine.board[4][5] = Some(GamePiece::new(PieceColor::White));
+        engine.board[4][3] = Some(GamePiece::new(PieceColor::White));
+
+        assert!(!engine.is_legal_prefix(&[]));
+        assert!(!engine.is_legal_prefix(&[Coordinate(4, 5)]));
+        assert!(!engine.is_legal_prefix(&[Coordinate(3, 6), Coordinate(1, 4)]));
+        assert!(!engine.is_legal_prefix(&[Coordinate(3, 6), Coordinate(5, 4), Coordinate(7, 2)]));
+        // the turn is over after the second hop
+        assert!(!engine.is_legal_prefix(&[
+            Coordinate(3, 6),
+            Coordinate(5, 4),
+            Coordinate(3, 2),
+            Coordinate(2, 1)
+        ]));
+    }
+
+    #[test]
+    fn apply_partial_jump_failure() {
+        let mut engine = GameEngine::new();
+        assert_eq!(
+            engine.apply_partial_jump(Move::new((0, 5), (1, 4))),
+            Err(MoveError::IllegalMove)
+        );
+        assert_eq!(
+            engine.apply_partial_jump(Move::new((6, 5), (8, 3))),
+            Err(MoveError::OffBoard)
+        );
+        assert_eq!(engine.move_count(), 0);
+    }
+
+    #[test]
+    fn render_ascii_success() {
+        let engine = GameEngine::new();
+        assert_eq!(
+            engine.render_ascii(),
+            ".w.w.w.w\nw.w.w.w.\n.w.w.w.w\n........\n........\nb.b.b.b.\n.b.b.b.b\nb.b.b.b.\n"
+        );
+    }
+
+    #[test]
+    fn render_with_hints_success() {
+        let engine = GameEngine::new();
+        let render = engine.render_with_hints();
+        // the seven opening moves share four destination squares
+        assert_eq!(engine.legal_moves().len(), 7);
+        assert_eq!(render.matches('*').count(), 4);
+        assert_eq!(render.lines().nth(4), Some(".*.*.*.*"));
+        assert_eq!(render.replace('*', "."), engine.render_ascii());
+    }
+
+    #[test]
+    fn legal_moves_into_success() {
+        let mut engine = GameEngine::new();
+        let mut buf = vec![Move::new((0, 0), (1, 1))];
+        engine.legal_moves_into(&mut buf);
+        assert_eq!(buf, engine.legal_moves());
+
+        for move_desired in [Move::new((2, 5), (3, 4)), Move::new((1, 2), (2, 3))].iter() {
+            engine.move_piece(move_desired).unwrap();
+            engine.legal_moves_into(&mut buf);
+            assert_eq!(buf, engine.legal_moves());
+        }
+    }
+
+    #[test]
+    fn reset_game_success() {
+        let mut engine = GameEngine::with_rules(Rules::international());
+        engine.move_piece(&Move::new((2, 5), (3, 4))).unwrap();
+        engine.move_piece(&Move::new((1, 2), (2, 3))).unwrap();
+        engine.reset_game();
+
+        assert_eq!(engine.rules(), Rules::international());
+        assert_eq!(engine.move_count(), 0);
+        assert_eq!(engine.current_turn(), PieceColor::Black);
+        assert!(engine.history().is_empty());
+        assert_eq!(
+            engine.zobrist_hash(),
+            GameEngine::with_rules(Rules::international()).zobrist_hash()
+        );
+    }
+
+    #[test]
+    fn reset_game_keeps_layout() {
+        let rules = RulesBuilder::new().orthogonal_moves(true).build();
+        let mut engine = GameEngine::with_rules(rules);
+        engine.initialize_turkish_pieces();
+        let start = engine.zobrist_hash();
+        engine.move_piece(&engine.legal_moves()[0]).unwrap();
+        engine.reset_game();
+        assert_eq!(engine.piece_count(PieceColor::Black), 16);
+        assert_eq!(engine.zobrist_hash(), start);
+
+        let mut engine = GameEngine::with_setup(2, 1).unwrap();
+        engine.move_piece(&Move::new((1, 6), (0, 5))).unwrap();
+        engine.reset_game();
+        assert_eq!(engine.piece_count(PieceColor::Black), 8);
+        assert_eq!(engine.piece_count(PieceColor::White), 4);
+    }
+
+    #[test]
+    fn with_move_applied_success() {
+        let engine = GameEngine::new();
+        let child = engine
+            .with_move_applied(&Move::new((2, 5), (3, 4)))
+            .unwrap();
+        assert_eq!(child.piece_at(Coordinate(2, 5)), None);
+        assert_eq!(
+            child.piece_at(Coordinate(3, 4)),
+            Some(GamePiece::new(PieceColor::Black))
+        );
+        assert_eq!(child.current_turn(), PieceColor::White);
+
+        assert_eq!(
+            engine.piece_at(Coordinate(2, 5)),
+            Some(GamePiece::new(PieceColor::Black))
+        );
+        assert_eq!(engine.current_turn(), PieceColor::Black);
+        assert_eq!(engine.move_count(), 0);
+        assert!(engine.history().is_empty());
+
+        assert_eq!(
+            engine.with_move_applied(&Move::new((2, 5), (2, 4))).err(),
+            Some(MoveError::IllegalMove)
+        );
+    }
+
+    #[test]
+    fn search_copy_success() {
+        let mut engine = GameEngine::new();
+        engine.move_piece(&Move::new((2, 5), (3, 4))).unwrap();
+
+        let mut copy = engine.search_copy();
+        assert_eq!(copy.zobrist_hash(), engine.zobrist_hash());
+        assert_eq!(copy.move_count(), 1);
+        assert!(copy.history().is_empty());
+
+        copy.move_piece(&Move::new((1, 2), (2, 3))).unwrap();
+        assert!(copy.history().is_empty());
+        assert_eq!(copy.undo(), None);
+        assert_eq!(engine.history().len(), 1);
+    }
+
+    #[test]
+    fn successors_success() {
+        let engine = GameEngine::new();
+        let successors = engine.successors();
+        assert_eq!(successors.len(), 7);
+        for (m, child) in successors {
+            assert_eq!(child.current_turn(), PieceColor::White);
+            assert_eq!(child.move_count(), 1);
+            assert_eq!(child.piece_at(m.from), None);
+            assert_eq!(
+                child.piece_at(m.to),
+                Some(GamePiece::new(PieceColor::Black))
+            );
+            assert_eq!(child.check_invariants(), Ok(()));
+        }
+        assert_eq!(engine.move_count(), 0);
+    }
+
+    #[test]
+    fn report_success() {
+        let engine = GameEngine::new();
+        assert_eq!(
+            engine.report(),
+            GameReport {
+                current_turn: PieceColor::Black,
+                move_count: 0,
+                black_men: 12,
+                black_kings: 0,
+                white_men: 12,
+                white_kings: 0,
+                capture_forced: false,
+                status: GameStatus::InProgress,
+                legal_move_count: 7,
+            }
+        );
+
+        let mut engine = GameEngine::with_rules(Rules::american());
+        engine.move_piece(&Move::new((2, 5), (3, 4))).unwrap();
+        engine.move_piece(&Move::new((1, 2), (2, 3))).unwrap();
+        let report = engine.report();
+        assert!(report.capture_forced);
+        assert_eq!(report.move_count, 2);
+        assert_eq!(report.legal_move_count, 1);
+    }
+
+    #[test]
+    fn undo_success() {
+        let mut engine = GameEngine::new();
+        assert_eq!(engine.undo(), None);
+
+        engine.move_piece(&Move::new((2, 5), (3, 4))).unwrap();
+        engine.move_piece(&Move::new((1, 2), (2, 3))).unwrap();
+        let before = engine.clone();
+        let result = engine.move_piece(&Move::new((3, 4), (1, 2))).unwrap();
+
+        let undone = engine.undo().unwrap();
+        assert_eq!(undone, result);
+        assert_eq!(
+            undone.captured_pieces,
+            vec![(Coordinate(2, 3), GamePiece::new(PieceColor::White))]
+        );
+        assert_eq!(
+            engine.piece_at(Coordinate(2, 3)),
+            Some(GamePiece::new(PieceColor::White))
+        );
+        assert_eq!(engine.piece_at(Coordinate(1, 2)), None);
+        assert_eq!(engine.current_turn(), PieceColor::Black);
+        assert_eq!(engine.move_count(), before.move_count());
+        assert_eq!(engine.white_captured(), 0);
+        assert_eq!(engine.zobrist_hash(), before.zobrist_hash());
+        assert_eq!(engine.history().len(), 2);
+    }
+
+    #[test]
+    fn undo_after_resign_failure() {
+        let mut engine = GameEngine::new();
+        engine.move_piece(&Move::new((2, 5), (3, 4))).unwrap();
+        engine.resign(PieceColor::White).unwrap();
+
+        assert_eq!(engine.undo(), None);
+        assert_eq!(engine.status(), GameStatus::Resigned(PieceColor::White));
+        assert_eq!(engine.history().len(), 1);
+    }
+
+    #[test]
+    fn undo_after_edit_failure() {
+        let mut engine = GameEngine::new();
+        engine.move_piece(&Move::new((2, 5), (3, 4))).unwrap();
+        engine.set_crowned(Coordinate(3, 4), true).unwrap();
+
+        assert_eq!(engine.undo(), None);
+        assert!(engine.is_crowned(Coordinate(3, 4)));
+        assert!(engine.history().is_empty());
+
+        engine.move_piece(&Move::new((1, 2), (2, 3))).unwrap();
+        engine.swap_all_colors();
+        assert_eq!(engine.undo(), None);
+
+        let mut engine = GameEngine::new();
+        engine.move_piece(&Move::new((2, 5), (3, 4))).unwrap();
+        let mut mirrored = engine.mirror();
+        assert!(mirrored.history().is_empty());
+        assert_eq!(mirrored.undo(), None);
+    }
+
+    #[test]
+    fn history_success() {
+        let mut engine = GameEngine::new();
+        assert!(engine.history().is_empty());
+
+        engine.move_piece(&Move::new((2, 5), (3, 4))).unwrap();
+        engine.move_piece(&Move::new((1, 2), (2, 3))).unwrap();
+        engine.move_piece(&Move::new((3, 4), (1, 2))).unwrap();
+
+        let history = engine.history();
+        assert_eq!(history.len(), 3);
+        assert_eq!(history[0].move_made, Move::new((2, 5), (3, 4)));
+        assert_eq!(history[0].captured, None);
+        assert_eq!(history[0].captured_pieces, vec![]);
+        assert_eq!(history[1].move_made, Move::new((1, 2), (2, 3)));
+        assert_eq!(history[2].move_made, Move::new((3, 4), (1, 2)));
+        assert_eq!(history[2].captured, Some(Coordinate(2, 3)));
+        assert_eq!(
+            history[2].captured_pieces,
+            vec![(Coordinate(2, 3), GamePiece::new(PieceColor::White))]
+        );
+        assert!(!history[2].crowned);
+    }
+
+    #[test]
+    fn history_deltas_success() {
+        let moves = [
+            Move::new((2, 5), (3, 4)),
+            Move::new((1, 2), (2, 3)),
+            Move::new((3, 4), (1, 2)),
+        ];
+        let mut engine = GameEngine::new();
+        engine.apply_moves(&moves).unwrap();
+
+        let deltas = engine.history_deltas();
+        assert_eq!(deltas.len(), moves.len());
+        assert_eq!(deltas[2].changes.len(), 3);
+
+        let mut rebuilt = GameEngine::new();
+        for (ply, delta) in deltas.iter().enumerate() {
+            rebuilt.apply_delta(delta);
+            let replayed = GameEngine::replay_to_ply(&moves, ply + 1).unwrap();
+            assert_eq!(rebuilt.board, replayed.board);
+        }
+
+        // the mirrored position keeps no moves, so it has no deltas to misapply
+        assert_eq!(engine.mirror().history_deltas(), vec![]);
+    }
+
+    #[test]
+    fn apply_moves_success() {
+        let mut engine = GameEngine::new();
+        let results = engine
+            .apply_moves(&[Move::new((0, 5), (1, 4)), Move::new((1, 2), (0, 3))])
+            .unwrap();
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[1].move_made, Move::new((1, 2), (0, 3)));
+        assert_eq!(engine.move_count(), 2);
+    }
+
+    #[test]
+    fn apply_moves_rollback() {
+        let mut engine = GameEngine::new();
+        engine.move_piece(&Move::new((6, 5), (7, 4))).unwrap();
+        let before = engine.clone();
+
+        let res = engine.apply_moves(&[
+            Move::new((1, 2), (0, 3)),
+            Move::new((0, 3), (1, 4)), // black is to move, not white
+            Move::new((0, 5), (1, 4)),
+        ]);
+        assert_eq!(res.err(), Some((1, MoveError::IllegalMove)));
+        assert_eq!(engine.board, before.board);
+        assert_eq!(engine.current_turn(), before.current_turn());
+        assert_eq!(engine.move_count(), before.move_count());
+        assert_eq!(engine.plies_since_progress(), before.plies_since_progress());
+    }
+
+    #[test]
+    fn coordinate_new_success() {
+        assert_eq!(Coordinate::new(7, 7), Some(Coordinate(7, 7)));
+        assert_eq!(Coordinate::new(0, 0), Some(Coordinate(0, 0)));
+    }
+
+    #[test]
+    fn coordinate_new_failure() {
+        assert_eq!(Coordinate::new(8, 0), None);
+        assert_eq!(Coordinate::new(0, 8), None);
+    }
+
+    #[test]
+    fn diff_success() {
+        let engine = GameEngine::new();
+        assert_eq!(engine.diff(&engine.clone()), vec![]);
+
+        let mut moved = engine.clone();
+        moved.move_piece(&Move::new((0, 5), (1, 4))).unwrap();
+        assert_eq!(
+            engine.diff(&moved),
+            vec![
+                (Coordinate(0, 5), None),
+                (Coordinate(1, 4), Some(GamePiece::new(PieceColor::Black))),
+            ]
+        );
+    }
+
+    #[test]
+    fn legal_moves_sorted_success() {
+        let mut first = GameEngine::new();
+        first
+            .apply_moves(&[
+                Move::new((0, 5), (1, 4)),
+                Move::new((1, 2), (0, 3)),
+                Move::new((6, 5), (7, 4)),
+                Move::new((7, 2), (6, 3)),
+            ])
+            .unwrap();
+        let mut second = GameEngine::new();
+        second
+            .apply_moves(&[
+                Move::new((6, 5), (7, 4)),
+                Move::new((7, 2), (6, 3)),
+                Move::new((0, 5), (1, 4)),
+                Move::new((1, 2), (0, 3)),
+            ])
+            .unwrap();
+
+        let sorted = first.legal_moves_sorted();
+        assert_eq!(sorted, second.legal_moves_sorted());
+        assert_eq!(sorted.len(), first.legal_moves().len());
+        let keys = sorted
+            .iter()
+            .map(|m| (m.from.to_square_number(8), m.to.to_square_number(8)))
+            .collect::<Vec<_>>();
+        assert!(keys.windows(2).all(|pair| pair[0] < pair[1]));
+        assert_eq!(sorted[0], Move::new((7, 6), (6, 5)));
+    }
+
+    #[test]
+    fn last_move_success() {
+        let mut engine = GameEngine::new();
+        assert_eq!(engine.last_move(), None);
+
+        engine.move_piece(&Move::new((0, 5), (1, 4))).unwrap();
+        assert_eq!(engine.last_move(), Some(Move::new((0, 5), (1, 4))));
+    }
 
     #[test]
-    fn should_crown_success() {
-        let engine = GameEngine::new();
-        let black = GamePiece::new(PieceColor::Black);
-        let res = engine.should_crown(black, Coordinate(3, 0));
-        assert!(res);
+    fn last_move_multi_jump() {
+        let mut engine = empty_engine(Rules::american());
+        engine.board[7][6] = Some(GamePiece::new(PieceColor::Black));
+        engine.board[6][5] = Some(GamePiece::new(PieceColor::White));
+        engine.board[4][3] = Some(GamePiece::new(PieceColor::White));
+
+        engine.move_piece(&Move::new((7, 6), (5, 4))).unwrap();
+        assert_eq!(engine.last_move(), Some(Move::new((7, 6), (5, 4))));
+        engine.move_piece(&Move::new((5, 4), (3, 2))).unwrap();
+        assert_eq!(engine.last_move(), Some(Move::new((7, 6), (3, 2))));
     }
 
     #[test]
-    fn should_crown_failure() {
-        let engine = GameEngine::new();
-        let black = GamePiece::new(PieceColor::Black);
-        let res_no_crown = engine.should_crown(black, Coordinate(5, 2));
-        assert_eq!(res_no_crown, false);
+    fn pass_turn_allowed() {
+        let mut engine = GameEngine::with_rules(RulesBuilder::new().allow_pass(true).build());
+        assert_eq!(engine.pass_turn(), Ok(()));
+        assert_eq!(engine.current_turn(), PieceColor::White);
+        assert_eq!(engine.move_count(), 1);
     }
 
     #[test]
-    fn crown_success() {
+    fn pass_turn_observed_success() {
+        let mut engine = GameEngine::with_rules(RulesBuilder::new().allow_pass(true).build());
+        let mut observer = RecordingObserver::default();
+        assert_eq!(engine.pass_turn_observed(&mut observer), Ok(()));
+        assert_eq!(engine.pass_turn_observed(&mut observer), Ok(()));
+        assert_eq!(observer.moves, 0);
+        assert_eq!(
+            observer.turn_changes,
+            vec![PieceColor::White, PieceColor::Black]
+        );
+
         let mut engine = GameEngine::new();
-        engine.initialize_pieces();
-        let crowned = engine.crown_piece(Coordinate(1, 0));
-        assert!(crowned);
+        let res = engine.pass_turn_observed(&mut observer);
+        assert_eq!(res, Err(MoveError::PassNotAllowed));
+        assert_eq!(observer.turn_changes.len(), 2);
     }
 
     #[test]
-    fn crown_failure() {
-        let mut engine = GameEngine::new();
-        engine.initialize_pieces();
-        let crowned = engine.crown_piece(Coordinate(2, 0));
-        assert_eq!(crowned, false);
+    fn pass_turn_draw_limit() {
+        let rules = RulesBuilder::new()
+            .allow_pass(true)
+            .draw_ply_limit(Some(4))
+            .build();
+        let mut engine = GameEngine::with_rules(rules);
+        for _ in 0..4 {
+            assert_eq!(engine.pass_turn(), Ok(()));
+        }
+        assert_eq!(engine.plies_since_progress(), 4);
+        assert_eq!(engine.status(), GameStatus::Draw);
+        assert_eq!(engine.pass_turn(), Err(MoveError::GameOver));
     }
 
     #[test]
-    fn is_crown_success() {
+    fn pass_turn_disallowed() {
         let mut engine = GameEngine::new();
-        engine.initialize_pieces();
-        let crowned = engine.crown_piece(Coordinate(1, 0));
-        assert!(crowned);
-        assert!(engine.is_crowned(Coordinate(1, 0)));
+        assert_eq!(engine.pass_turn(), Err(MoveError::PassNotAllowed));
+        assert_eq!(engine.current_turn(), PieceColor::Black);
+        assert_eq!(engine.move_count(), 0);
     }
 
     #[test]
-    fn is_crown_failure() {
-        let mut engine = GameEngine::new();
-        engine.initialize_pieces();
-        assert_eq!(engine.is_crowned(Coordinate(1, 0)), false);
+    fn can_step_success() {
+        let engine = GameEngine::new();
+        assert!(engine.can_step(Coordinate(0, 5), Coordinate(1, 4)));
+        assert!(engine.can_step(Coordinate(2, 5), Coordinate(3, 4)));
+        // white pieces are checked even though it is black's turn
+        assert!(engine.can_step(Coordinate(1, 2), Coordinate(0, 3)));
     }
 
     #[test]
-    fn advance_turn() {
-        let mut engine = GameEngine::new();
-        engine.advance_turn();
-        assert_eq!(engine.current_turn(), PieceColor::White);
+    fn can_step_failure() {
+        let engine = GameEngine::new();
+        assert!(!engine.can_step(Coordinate(1, 0), Coordinate(0, 1)));
+        assert!(!engine.can_step(Coordinate(0, 5), Coordinate(2, 3)));
+        assert!(!engine.can_step(Coordinate(0, 4), Coordinate(1, 3)));
+        assert!(!engine.can_step(Coordinate(8, 5), Coordinate(7, 4)));
     }
 
     #[test]
-    fn move_count_success() {
+    fn can_jump_success() {
         let mut engine = GameEngine::new();
-        engine.advance_turn();
-        engine.advance_turn();
-        assert_eq!(engine.move_count(), 2);
+        engine.board[1][4] = Some(GamePiece::new(PieceColor::White));
+        assert!(engine.can_jump(Coordinate(0, 5), Coordinate(2, 3)));
+        assert!(engine.can_jump(Coordinate(2, 5), Coordinate(0, 3)));
+        assert!(!engine.can_jump(Coordinate(0, 5), Coordinate(1, 4)));
     }
 
     #[test]
-    fn move_targets_success() {
-        let coord = Coordinate(0, 5);
-        let targets = coord.move_targets_from().collect::<Vec<Coordinate>>();
-        assert_eq!(targets, [Coordinate(1, 6), Coordinate(1, 4)]);
+    fn can_jump_failure() {
+        let engine = GameEngine::new();
+        // (1, 6) is a friendly piece, so black can't jump it
+        assert!(!engine.can_jump(Coordinate(0, 7), Coordinate(2, 5)));
+        assert!(!engine.can_jump(Coordinate(0, 5), Coordinate(2, 3)));
     }
 
     #[test]
-    fn valid_from_success() {
-        let coord_1 = Coordinate(0, 5);
-        let coord_2 = Coordinate(2, 5);
+    fn jump_over_friendly_piece_failure() {
+        let mut engine = empty_engine(Rules::default());
+        engine.board[5][4] = Some(GamePiece::new(PieceColor::Black));
+        engine.board[4][3] = Some(GamePiece::new(PieceColor::Black));
+        engine.board[0][1] = Some(GamePiece::new(PieceColor::White));
 
-        let mut engine = GameEngine::new();
-        engine.initialize_pieces();
-        let move_1 = engine.valid_moves_from(coord_1);
-        let move_2 = engine.valid_moves_from(coord_2);
-        assert_eq!(
-            move_1,
-            [Move {
-                from: Coordinate(0, 5),
-                to: Coordinate(1, 4),
-            }]
-        );
-        assert_eq!(
-            move_2,
-            [
-                Move {
-                    from: Coordinate(2, 5),
-                    to: Coordinate(3, 4),
-                },
-                Move {
-                    from: Coordinate(2, 5),
-                    to: Coordinate(1, 4),
-                }
-            ]
-        );
+        assert!(!engine.can_jump(Coordinate(5, 4), Coordinate(3, 2)));
+        assert!(!engine.legal_moves().contains(&Move::new((5, 4), (3, 2))));
+        let res = engine.move_piece(&Move::new((5, 4), (3, 2)));
+        assert_eq!(res, Err(MoveError::IllegalMove));
     }
 
     #[test]
-    fn valid_from_failure() {
-        let coord = Coordinate(1, 0);
-        let mut engine = GameEngine::new();
-        engine.initialize_pieces();
-        let move_res = engine.valid_moves_from(coord);
-        assert_eq!(move_res, vec![]);
+    fn jump_onto_friendly_piece_failure() {
+        let rules = RulesBuilder::new().mandatory_capture(true).build();
+        let mut engine = empty_engine(rules);
+        engine.board[3][4] = Some(GamePiece::new(PieceColor::Black));
+        engine.board[2][3] = Some(GamePiece::new(PieceColor::White));
+        engine.board[1][2] = Some(GamePiece::new(PieceColor::Black));
+        engine.board[6][1] = Some(GamePiece::new(PieceColor::White));
+
+        assert!(!engine.can_jump(Coordinate(3, 4), Coordinate(1, 2)));
+        assert!(!engine.has_capture());
+        let res = engine.move_piece(&Move::new((3, 4), (1, 2)));
+        assert_eq!(res, Err(MoveError::IllegalMove));
+        assert_eq!(engine.board[2][3], Some(GamePiece::new(PieceColor::White)));
+        assert_eq!(engine.board[1][2], Some(GamePiece::new(PieceColor::Black)));
     }
 
     #[test]
-    fn legal_moves_black_success() {
+    fn jump_onto_occupied_square_failure() {
+        // from the opening, (2, 3) looks hanging but (3, 2) behind it is held by white
         let mut engine = GameEngine::new();
-        engine.initialize_pieces();
-        let moves = engine.legal_moves();
-        assert_eq!(
-            moves,
-            [
-                Move {
-                    from: Coordinate(0, 5),
-                    to: Coordinate(1, 4),
-                },
-                Move {
-                    from: Coordinate(2, 5),
-                    to: Coordinate(3, 4),
-                },
-                Move {
-                    from: Coordinate(2, 5),
-                    to: Coordinate(1, 4),
-                },
-                Move {
-                    from: Coordinate(4, 5),
-                    to: Coordinate(5, 4),
-                },
-                Move {
-                    from: Coordinate(4, 5),
-                    to: Coordinate(3, 4),
-                },
-                Move {
-                    from: Coordinate(6, 5),
-                    to: Coordinate(7, 4),
-                },
-                Move {
-                    from: Coordinate(6, 5),
-                    to: Coordinate(5, 4),
-                }
-            ]
-        );
+        engine.move_piece(&Move::new((0, 5), (1, 4))).unwrap();
+        engine.move_piece(&Move::new((1, 2), (2, 3))).unwrap();
+
+        assert!(!engine.can_jump(Coordinate(1, 4), Coordinate(3, 2)));
+        let res = engine.move_piece(&Move::new((1, 4), (3, 2)));
+        assert_eq!(res, Err(MoveError::IllegalMove));
+        assert_eq!(engine.board[2][3], Some(GamePiece::new(PieceColor::White)));
+        assert_eq!(engine.board[3][2], Some(GamePiece::new(PieceColor::White)));
     }
 
     #[test]
-    fn legal_moves_white_success() {
-        let mut engine = GameEngine::new();
-        engine.initialize_pieces();
-        engine.advance_turn();
-        let moves = engine.legal_moves();
-        assert_eq!(
-            moves,
-            [
-                Move {
-                    from: Coordinate(1, 2),
-                    to: Coordinate(0, 3),
-                },
-                Move {
-                    from: Coordinate(1, 2),
-                    to: Coordinate(2, 3),
-                },
-                Move {
-                    from: Coordinate(3, 2),
-                    to: Coordinate(2, 3),
-                },
-                Move {
-                    from: Coordinate(3, 2),
-                    to: Coordinate(4, 3),
-                },
-                Move {
-                    from: Coordinate(5, 2),
-                    to: Coordinate(4, 3),
-                },
-                Move {
-                    from: Coordinate(5, 2),
-                    to: Coordinate(6, 3),
-                },
-                Move {
-                    from: Coordinate(7, 2),
-                    to: Coordinate(6, 3),
+    fn random_position_success() {
+        let mut rng = StdRng::seed_from_u64(7);
+        for _ in 0..200 {
+            let engine = GameEngine::random_position(&mut rng);
+            assert_eq!(engine.check_invariants(), Ok(()));
+            for col in 0..8 {
+                for row in 0..8 {
+                    if let Some(piece) = engine.board[col][row] {
+                        assert_eq!((col + row) % 2, 1);
+                        assert!(piece.crowned || !engine.should_crown(piece, Coordinate(col, row)));
+                    }
                 }
-            ]
-        );
+            }
+            for &color in &[PieceColor::Black, PieceColor::White] {
+                assert!((1..=12).contains(&engine.piece_count(color)));
+            }
+        }
     }
 
     #[test]
-    fn jump_targets_success() {
-        let coord = Coordinate(3, 3);
-        let targets = coord.jump_targets_from().collect::<Vec<Coordinate>>();
-        assert_eq!(
-            targets,
-            [
-                Coordinate(5, 1),
-                Coordinate(5, 5),
-                Coordinate(1, 1),
-                Coordinate(1, 5)
-            ]
-        );
+    fn check_invariants_success() {
+        let engine = GameEngine::new();
+        assert_eq!(engine.check_invariants(), Ok(()));
     }
 
     #[test]
-    fn jump_moves_validation_success() {
+    fn check_invariants_failure() {
+        let mut engine = GameEngine::new();
+        engine.board[0][0] = Some(GamePiece::new(PieceColor::Black));
+        assert!(engine.check_invariants().is_err());
+
         let mut engine = GameEngine::new();
-        engine.initialize_pieces();
         engine.board[1][4] = Some(GamePiece::new(PieceColor::White));
-        let moves = engine.legal_moves();
+        assert!(engine.check_invariants().is_err());
+
+        let mut engine = GameEngine::new();
+        engine.jumping_piece = Some(Coordinate(1, 2));
+        assert!(engine.check_invariants().is_err());
+    }
+
+    #[test]
+    fn replay_to_ply_success() {
+        let moves = [
+            Move::new((2, 5), (3, 4)),
+            Move::new((1, 2), (2, 3)),
+            Move::new((3, 4), (1, 2)),
+            Move::new((0, 1), (2, 3)),
+            Move::new((0, 5), (1, 4)),
+        ];
+
+        let engine = GameEngine::replay_to_ply(&moves, 2).unwrap();
+        assert_eq!(engine.current_turn(), PieceColor::Black);
+        assert!(engine.piece_at(Coordinate(3, 4)).is_some());
+        assert!(engine.piece_at(Coordinate(2, 3)).is_some());
+        assert!(engine.piece_at(Coordinate(1, 2)).is_none());
+        assert_eq!(engine.piece_count(PieceColor::White), 12);
+
+        let engine = GameEngine::replay_to_ply(&moves, 5).unwrap();
+        assert_eq!(engine.piece_count(PieceColor::White), 11);
+        assert_eq!(engine.piece_count(PieceColor::Black), 11);
         assert_eq!(
-            moves,
-            [
-                Move {
-                    from: Coordinate(0, 5),
-                    to: Coordinate(2, 3),
-                },
-                Move {
-                    from: Coordinate(2, 5),
-                    to: Coordinate(0, 3)
-                },
-                Move {
-                    from: Coordinate(2, 5),
-                    to: Coordinate(3, 4)
-                },
-                Move {
-                    from: Coordinate(4, 5),
-                    to: Coordinate(5, 4)
-                },
-                Move {
-                    from: Coordinate(4, 5),
-                    to: Coordinate(3, 4)
-                },
-                Move {
-                    from: Coordinate(6, 5),
-                    to: Coordinate(7, 4)
-                },
-                Move {
-                    from: Coordinate(6, 5),
-                    to: Coordinate(5, 4)
-                }
-            ]
+            GameEngine::replay_to_ply(&moves, 0).unwrap().move_count(),
+            0
+        );
+        assert_eq!(
+            GameEngine::replay_to_ply(&moves, 6).err(),
+            Some(MoveError::IllegalMove)
         );
     }
 
     #[test]
-    fn test_basic_move_success() {
-        let mut engine = GameEngine::new();
-        engine.initialize_pieces();
-        let res = engine.move_piece(&Move::new((0, 5), (1, 4)));
-        assert!(res.is_ok());
+    fn new_game_loser_starts_success() {
+        let engine = GameEngine::new_game_loser_starts(PieceColor::Black);
+        assert_eq!(engine.current_turn(), PieceColor::White);
+        assert_eq!(engine.piece_count(PieceColor::White), 12);
+        assert!(engine
+            .legal_moves()
+            .iter()
+            .all(|m| engine.piece_at(m.from).unwrap().color == PieceColor::White));
 
-        let old = engine.board[0][5];
-        let new = engine.board[1][4];
-        assert_eq!(old, None);
+        let engine = GameEngine::new_game_loser_starts(PieceColor::White);
+        assert_eq!(engine.current_turn(), PieceColor::Black);
+
+        // the loser keeps the first move when the rematch is reset
+        let mut engine = GameEngine::new_game_loser_starts(PieceColor::Black);
+        engine.move_piece(&Move::new((1, 2), (0, 3))).unwrap();
+        engine.reset_game();
+        assert_eq!(engine.current_turn(), PieceColor::White);
+        assert_eq!(engine.move_count(), 0);
+    }
+
+    #[test]
+    fn with_setup_success() {
+        let engine = GameEngine::with_setup(3, 3).unwrap();
+        assert_eq!(engine.board, GameEngine::new().board);
+
+        let handicap = GameEngine::with_setup(2, 3).unwrap();
+        assert_eq!(handicap.piece_count(PieceColor::Black), 8);
+        assert_eq!(handicap.piece_count(PieceColor::White), 12);
+        assert_eq!(handicap.piece_at(Coordinate(0, 5)), None);
         assert_eq!(
-            new,
-            Some(GamePiece {
-                color: PieceColor::Black,
-                crowned: false
-            })
+            handicap.piece_at(Coordinate(1, 6)),
+            Some(GamePiece::new(PieceColor::Black))
         );
+    }
 
-        // fail to perform illegal move
-        let res = engine.move_piece(&Move::new((1, 4), (2, 4))); // can't move horiz
-        assert!(!res.is_ok());
-        assert_eq!(engine.board[2][4], None);
+    #[test]
+    fn with_setup_overlap_failure() {
+        assert!(GameEngine::with_setup(4, 4).is_some());
+        assert!(GameEngine::with_setup(5, 4).is_none());
+    }
+
+    #[test]
+    fn board_size_bounds() {
+        let engine = GameEngine::new();
+        let last = BOARD_SIZE - 1;
+        assert!(Coordinate(last, last).on_board());
+        assert!(!Coordinate(BOARD_SIZE, 0).on_board());
+        assert_eq!(engine.get_piece(Coordinate(last, last)), Ok(None));
+        assert_eq!(engine.get_piece(Coordinate(0, BOARD_SIZE)), Err(()));
+
+        let rows = BOARD_SIZE as u8 / 2;
+        assert!(GameEngine::with_setup(rows, rows).is_some());
+        assert!(GameEngine::with_setup(rows + 1, rows).is_none());
     }
 }
@@ -0,0 +1,265 @@
+/// Number of plies without a capture or crowning after which the game is drawn, under every
+/// preset.
+pub const DRAW_PLY_LIMIT: u32 = 80;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Rules {
+    pub mandatory_capture: bool,
+    pub men_capture_backward: bool,
+    pub flying_kings: bool,
+    pub longest_capture: bool,
+    pub allow_pass: bool,
+    /// A piece which captures keeps the turn while it can capture again, so one move may take
+    /// several pieces. Off, every capture ends the turn as in the original engine.
+    pub multi_jump: bool,
+    /// Captured pieces stay on the board, and cannot be jumped again, until the multi-jump ends.
+    pub deferred_capture_removal: bool,
+    /// A man reaching the far row during a capture is crowned and ends the turn there.
+    pub crowning_ends_capture: bool,
+    /// Pieces move and capture along ranks and files, as in Turkish draughts, instead of diagonals.
+    pub orthogonal_moves: bool,
+    /// Plies without a capture or crowning after which the game is drawn, None to never draw this way.
+    pub draw_ply_limit: Option<u32>,
+}
+
+impl Rules {
+    /// american method gives the rules of American checkers (English draughts).
+    ///
+    /// #Return
+    ///
+    /// Returns the instance of type Rules with mandatory capture and short kings.
+    pub fn american() -> Rules {
+        Rules {
+            mandatory_capture: true,
+            men_capture_backward: false,
+            flying_kings: false,
+            longest_capture: false,
+            allow_pass: false,
+            multi_jump: true,
+            deferred_capture_removal: false,
+            crowning_ends_capture: true,
+            orthogonal_moves: false,
+            draw_ply_limit: Some(DRAW_PLY_LIMIT),
+        }
+    }
+
+    /// international method gives the rules of international draughts.
+    ///
+    /// #Return
+    ///
+    /// Returns the instance of type Rules with every capture rule and flying kings enabled.
+    pub fn international() -> Rules {
+        Rules {
+            mandatory_capture: true,
+            men_capture_backward: true,
+            flying_kings: true,
+            longest_capture: true,
+            allow_pass: false,
+            multi_jump: true,
+            deferred_capture_removal: true,
+            crowning_ends_capture: false,
+            orthogonal_moves: false,
+            draw_ply_limit: Some(DRAW_PLY_LIMIT),
+        }
+    }
+}
+
+impl Default for Rules {
+    /// default method gives the casual rules the engine has always played by.
+    ///
+    /// #Return
+    ///
+    /// Returns the instance of type Rules where captures are optional and men may capture backward.
+    fn default() -> Rules {
+        Rules {
+            mandatory_capture: false,
+            men_capture_backward: true,
+            flying_kings: false,
+            longest_capture: false,
+            allow_pass: false,
+            multi_jump: true,
+            deferred_capture_removal: false,
+            crowning_ends_capture: true,
+            orthogonal_moves: false,
+            draw_ply_limit: Some(DRAW_PLY_LIMIT),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct RulesBuilder {
+    rules: Rules,
+}
+
+impl RulesBuilder {
+    /// new method creates a new instance of RulesBuilder starting from the default rules.
+    ///
+    /// #Return
+    ///
+    /// Returns the instance of type RulesBuilder.
+    pub fn new() -> RulesBuilder {
+        RulesBuilder::default()
+    }
+
+    /// from_rules method creates a new instance of RulesBuilder starting from a preset.
+    ///
+    /// #Arguments
+    ///
+    /// rules - a parameter of type Rules to be adjusted.
+    ///
+    /// #Return
+    ///
+    /// Returns the instance of type RulesBuilder.
+    pub fn from_rules(rules: Rules) -> RulesBuilder {
+        RulesBuilder { rules }
+    }
+
+    /// mandatory_capture method sets whether a capture must be taken when available.
+    ///
+    /// #Arguments
+    ///
+    /// enabled - a bool value turning the rule on or off.
+    ///
+    /// #Return
+    ///
+    /// Returns the updated RulesBuilder.
+    pub fn mandatory_capture(mut self, enabled: bool) -> RulesBuilder {
+        self.rules.mandatory_capture = enabled;
+        self
+    }
+
+    /// men_capture_backward method sets whether uncrowned pieces may capture backward.
+    ///
+    /// #Arguments
+    ///
+    /// enabled - a bool value turning the rule on or off.
+    ///
+    /// #Return
+    ///
+    /// Returns the updated RulesBuilder.
+    pub fn men_capture_backward(mut self, enabled: bool) -> RulesBuilder {
+        self.rules.men_capture_backward = enabled;
+        self
+    }
+
+    /// flying_kings method sets whether kings move and capture any distance along a diagonal.
+    ///
+    /// #Arguments
+    ///
+    /// enabled - a bool value turning the rule on or off.
+    ///
+    /// #Return
+    ///
+    /// Returns the updated RulesBuilder.
+    pub fn flying_kings(mut self, enabled: bool) -> RulesBuilder {
+        self.rules.flying_kings = enabled;
+        self
+    }
+
+    /// longest_capture method sets whether only the captures taking the most pieces are allowed.
+    ///
+    /// #Arguments
+    ///
+    /// enabled - a bool value turning the rule on or off.
+    ///
+    /// #Return
+    ///
+    /// Returns the updated RulesBuilder.
+    pub fn longest_capture(mut self, enabled: bool) -> RulesBuilder {
+        self.rules.longest_capture = enabled;
+        self
+    }
+
+    /// allow_pass method sets whether a player may pass the turn without moving.
+    ///
+    /// #Arguments
+    ///
+    /// enabled - a bool value turning the rule on or off.
+    ///
+    /// #Return
+    ///
+    /// Returns the updated RulesBuilder.
+    pub fn allow_pass(mut self, enabled: bool) -> RulesBuilder {
+        self.rules.allow_pass = enabled;
+        self
+    }
+
+    /// multi_jump method sets whether a capturing piece keeps the turn while it can capture again.
+    ///
+    /// #Arguments
+    ///
+    /// enabled - a bool value turning the rule on or off.
+    ///
+    /// #Return
+    ///
+    /// Returns the updated RulesBuilder.
+    pub fn multi_jump(mut self, enabled: bool) -> RulesBuilder {
+        self.rules.multi_jump = enabled;
+        self
+    }
+
+    /// deferred_capture_removal method sets whether captured pieces are removed only when the turn ends.
+    ///
+    /// #Arguments
+    ///
+    /// enabled - a bool value turning the rule on or off.
+    ///
+    /// #Return
+    ///
+    /// Returns the updated RulesBuilder.
+    pub fn deferred_capture_removal(mut self, enabled: bool) -> RulesBuilder {
+        self.rules.deferred_capture_removal = enabled;
+        self
+    }
+
+    /// crowning_ends_capture method sets whether a man reaching the far row mid-capture stops there.
+    ///
+    /// #Arguments
+    ///
+    /// enabled - a bool value turning the rule on or off.
+    ///
+    /// #Return
+    ///
+    /// Returns the updated RulesBuilder.
+    pub fn crowning_ends_capture(mut self, enabled: bool) -> RulesBuilder {
+        self.rules.crowning_ends_capture = enabled;
+        self
+    }
+
+    /// orthogonal_moves method sets whether pieces move along ranks and files instead of diagonals.
+    ///
+    /// #Arguments
+    ///
+    /// enabled - a bool value turning the rule on or off.
+    ///
+    /// #Return
+    ///
+    /// Returns the updated RulesBuilder.
+    pub fn orthogonal_moves(mut self, enabled: bool) -> RulesBuilder {
+        self.rules.orthogonal_moves = enabled;
+        self
+    }
+
+    /// draw_ply_limit method sets how many plies without progress draw the game.
+    ///
+    /// #Arguments
+    ///
+    /// limit - an Option<u32> value with the ply limit, None to disable draw by inactivity.
+    ///
+    /// #Return
+    ///
+    /// Returns the updated RulesBuilder.
+    pub fn draw_ply_limit(mut self, limit: Option<u32>) -> RulesBuilder {
+        self.rules.draw_ply_limit = limit;
+        self
+    }
+
+    /// build method finishes the configuration.
+    ///
+    /// #Return
+    ///
+    /// Returns the configured Rules.
+    pub fn build(self) -> Rules {
+        self.rules
+    }
+}
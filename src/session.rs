@@ -0,0 +1,314 @@
+use super::board::{Coordinate, GamePiece, Move, PieceColor};
+use super::game::{GameEngine, GameStatus, MoveError, MoveResult};
+use super::rules::Rules;
+#[cfg(not(feature = "std"))]
+use alloc::collections::VecDeque;
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+#[cfg(not(feature = "std"))]
+use core::cell::{RefCell, RefMut};
+#[cfg(feature = "std")]
+use std::collections::VecDeque;
+#[cfg(feature = "std")]
+use std::sync::{Mutex, MutexGuard, PoisonError};
+
+/// The cell holding the cache of a GameSession, a Mutex so sessions can be shared between threads.
+#[cfg(feature = "std")]
+type CacheCell = Mutex<LegalMovesCache>;
+#[cfg(not(feature = "std"))]
+type CacheCell = RefCell<LegalMovesCache>;
+
+/// Number of positions whose legal moves a GameSession remembers.
+pub const MOVE_CACHE_SIZE: usize = 16;
+
+/// LegalMovesCache remembers the legal moves of recently seen positions, keyed by zobrist_hash.
+///
+/// The least recently used position is dropped once the cache is full. Positions part way
+/// through a multi-jump are never cached.
+#[derive(Clone)]
+pub struct LegalMovesCache {
+    capacity: usize,
+    entries: VecDeque<(u64, Vec<Move>)>,
+}
+
+impl LegalMovesCache {
+    /// new method creates an empty cache.
+    ///
+    /// #Arguments
+    ///
+    /// capacity - an usize parameter for the number of positions to remember.
+    ///
+    /// #Return
+    ///
+    /// Returns the instance of type LegalMovesCache.
+    pub fn new(capacity: usize) -> LegalMovesCache {
+        LegalMovesCache {
+            capacity,
+            entries: VecDeque::with_capacity(capacity),
+        }
+    }
+
+    /// legal_moves method gives the legal moves of a position, computing them only on a miss.
+    ///
+    /// #Arguments
+    ///
+    /// engine - A GameEngine type reference for the position.
+    ///
+    /// #Return
+    ///
+    /// Returns vector containing the legal moves.
+    pub fn legal_moves(&mut self, engine: &GameEngine) -> Vec<Move> {
+        if engine.jumping_piece().is_some() || self.capacity == 0 {
+            return engine.legal_moves();
+        }
+
+        let hash = engine.zobrist_hash();
+        if let Some(index) = self.entries.iter().position(|&(key, _)| key == hash) {
+            let entry = self.entries.remove(index).unwrap();
+            let moves = entry.1.clone();
+            self.entries.push_back(entry);
+            return moves;
+        }
+
+        let moves = engine.legal_moves();
+        if self.entries.len() == self.capacity {
+            self.entries.pop_front();
+        }
+        self.entries.push_back((hash, moves.clone()));
+        moves
+    }
+
+    /// len method counts the positions currently remembered.
+    ///
+    /// #Return
+    ///
+    /// Returns an usize value denoting the number of cached positions.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// is_empty method checks if no position is remembered.
+    ///
+    /// #Return
+    ///
+    /// Returns a bool value, true for an empty cache.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+/// GameSession owns a single game, so a server can hold many games without shared state.
+pub struct GameSession {
+    engine: GameEngine,
+    cache: CacheCell,
+}
+
+impl Clone for GameSession {
+    /// clone method copies the game along with the moves cached so far.
+    ///
+    /// #Return
+    ///
+    /// Returns the instance of type GameSession.
+    fn clone(&self) -> GameSession {
+        GameSession {
+            engine: self.engine.clone(),
+            cache: CacheCell::new(self.cache().clone()),
+        }
+    }
+}
+
+impl Default for GameSession {
+    /// default method creates a new session with the default rules.
+    ///
+    /// #Return
+    ///
+    /// Returns the instance of type GameSession.
+    fn default() -> GameSession {
+        GameSession::with_rules(Rules::default())
+    }
+}
+
+impl GameSession {
+    /// new method creates a new session with the default rules.
+    ///
+    /// #Return
+    ///
+    /// Returns the instance of type GameSession.
+    pub fn new() -> GameSession {
+        GameSession::default()
+    }
+
+    /// with_rules method creates a new session playing by the given rules.
+    ///
+    /// #Arguments
+    ///
+    /// rules - a parameter of type Rules to play by.
+    ///
+    /// #Return
+    ///
+    /// Returns the instance of type GameSession.
+    pub fn with_rules(rules: Rules) -> GameSession {
+        GameSession {
+            engine: GameEngine::with_rules(rules),
+            cache: CacheCell::new(LegalMovesCache::new(MOVE_CACHE_SIZE)),
+        }
+    }
+
+    /// cache method locks the legal moves cache for a lookup.
+    ///
+    /// #Return
+    ///
+    /// Returns a guard of the LegalMovesCache. A cache poisoned by a panic is still used, since
+    /// a half made update leaves at worst a missing entry.
+    #[cfg(feature = "std")]
+    fn cache(&self) -> MutexGuard<'_, LegalMovesCache> {
+        self.cache.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// cache method borrows the legal moves cache for a lookup.
+    ///
+    /// #Return
+    ///
+    /// Returns a mutable borrow of the LegalMovesCache.
+    #[cfg(not(feature = "std"))]
+    fn cache(&self) -> RefMut<'_, LegalMovesCache> {
+        self.cache.borrow_mut()
+    }
+
+    /// engine method gives read access to the game for any other query.
+    ///
+    /// #Return
+    ///
+    /// Returns a reference of type GameEngine.
+    pub fn engine(&self) -> &GameEngine {
+        &self.engine
+    }
+
+    /// move_piece method makes the move desired by the player to move.
+    ///
+    /// #Arguments
+    ///
+    /// move_desired - a reference of type Move which holds the move to be made.
+    ///
+    /// #Return
+    ///
+    /// Returns the instance of type MoveResult denoting the result.
+    pub fn move_piece(&mut self, move_desired: &Move) -> Result<MoveResult, MoveError> {
+        self.engine.move_piece(move_desired)
+    }
+
+    /// resign method ends the game with a win for the opponent of the resigning color.
+    ///
+    /// #Arguments
+    ///
+    /// color - a parameter of type PieceColor denoting the player who resigns.
+    ///
+    /// #Return
+    ///
+    /// Returns the unit type, or MoveError::GameOver if the game has already ended.
+    pub fn resign(&mut self, color: PieceColor) -> Result<(), MoveError> {
+        self.engine.resign(color)
+    }
+
+    /// piece_at method gives the piece on a square.
+    ///
+    /// #Arguments
+    ///
+    /// coord - a parameter of type Coordinate to look at.
+    ///
+    /// #Return
+    ///
+    /// Returns the GamePiece wrapped in Option, None for an empty or off-board square.
+    pub fn piece_at(&self, coord: Coordinate) -> Option<GamePiece> {
+        self.engine.piece_at(coord)
+    }
+
+    /// current_turn method tells the player to move.
+    ///
+    /// #Return
+    ///
+    /// Returns the PieceColor of the player to move.
+    pub fn current_turn(&self) -> PieceColor {
+        self.engine.current_turn()
+    }
+
+    /// legal_moves method gives the moves the player to move can make, cached per position.
+    ///
+    /// #Return
+    ///
+    /// Returns vector containing the legal moves.
+    pub fn legal_moves(&self) -> Vec<Move> {
+        self.cache().legal_moves(&self.engine)
+    }
+
+    /// status method tells whether the game is still being played and how it ended.
+    ///
+    /// #Return
+    ///
+    /// Returns the GameStatus of the game.
+    pub fn status(&self) -> GameStatus {
+        self.engine.status()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::board::{Coordinate, Move, PieceColor};
+    use super::super::game::GameEngine;
+    use super::super::game::GameStatus;
+    use super::{GameSession, LegalMovesCache};
+
+    #[test]
+    fn independent_sessions() {
+        let mut first = GameSession::new();
+        let second = GameSession::new();
+
+        first.move_piece(&Move::new((0, 5), (1, 4))).unwrap();
+        assert_eq!(first.current_turn(), PieceColor::White);
+        assert!(first.piece_at(Coordinate(1, 4)).is_some());
+
+        assert_eq!(second.current_turn(), PieceColor::Black);
+        assert!(second.piece_at(Coordinate(1, 4)).is_none());
+        assert!(second.piece_at(Coordinate(0, 5)).is_some());
+        assert_eq!(second.legal_moves().len(), 7);
+        assert_eq!(second.status(), GameStatus::InProgress);
+    }
+
+    #[test]
+    fn cached_legal_moves_success() {
+        let mut session = GameSession::new();
+        let moves = [
+            Move::new((2, 5), (3, 4)),
+            Move::new((1, 2), (2, 3)),
+            Move::new((3, 4), (1, 2)),
+            Move::new((0, 1), (2, 3)),
+        ];
+        for move_desired in moves.iter() {
+            assert_eq!(session.legal_moves(), session.engine().legal_moves());
+            assert_eq!(session.legal_moves(), session.engine().legal_moves());
+            session.move_piece(move_desired).unwrap();
+        }
+        assert_eq!(session.legal_moves(), session.engine().legal_moves());
+    }
+
+    #[test]
+    fn cache_eviction_success() {
+        let mut cache = LegalMovesCache::new(2);
+        let mut engine = GameEngine::new();
+        let opening = cache.legal_moves(&engine);
+        engine.move_piece(&Move::new((0, 5), (1, 4))).unwrap();
+        cache.legal_moves(&engine);
+        engine.move_piece(&Move::new((1, 2), (0, 3))).unwrap();
+        cache.legal_moves(&engine);
+        assert_eq!(cache.len(), 2);
+
+        assert_eq!(cache.legal_moves(&GameEngine::new()), opening);
+        assert_eq!(cache.len(), 2);
+    }
+
+    #[test]
+    fn session_is_send_and_sync() {
+        fn assert_send_sync<T: Send + Sync>() {}
+        assert_send_sync::<GameSession>();
+    }
+}